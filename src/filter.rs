@@ -0,0 +1,55 @@
+use globset::{Glob, GlobSet, GlobSetBuilder};
+
+const DEFAULT_EXCLUDE: &[&str] = &["**/node_modules/**"];
+
+/// Filter files to transform by `include` and `exclude` glob patterns.
+pub struct FileFilter {
+    include: Option<GlobSet>,
+    exclude: GlobSet,
+}
+
+impl FileFilter {
+    /// Returns `true` if the file should be transformed.
+    ///
+    /// - Excluded files are never transformed.
+    /// - If `include` is provided, only matched files are transformed.
+    pub fn is_match(&self, filename: &str) -> bool {
+        if self.exclude.is_match(filename) {
+            return false;
+        }
+
+        self.include
+            .as_ref()
+            .is_none_or(|include| include.is_match(filename))
+    }
+}
+
+fn build_glob_set(patterns: &[String]) -> Result<GlobSet, globset::Error> {
+    let mut builder = GlobSetBuilder::new();
+    for pattern in patterns.iter() {
+        builder.add(Glob::new(pattern)?);
+    }
+    builder.build()
+}
+
+/// Returns a file filter.
+///
+/// If `exclude` is not provided, `node_modules` are excluded by default.
+pub fn file_filter(
+    include: &Option<Vec<String>>,
+    exclude: &Option<Vec<String>>,
+) -> Result<FileFilter, globset::Error> {
+    let default_exclude = DEFAULT_EXCLUDE
+        .iter()
+        .map(|pattern| pattern.to_string())
+        .collect::<Vec<String>>();
+
+    Ok(FileFilter {
+        include: include.as_deref().map(build_glob_set).transpose()?,
+        exclude: build_glob_set(exclude.as_deref().unwrap_or(&default_exclude))?,
+    })
+}
+
+#[cfg(test)]
+#[path = "./tests/filter.rs"]
+mod tests;
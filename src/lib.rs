@@ -1,38 +1,26 @@
-use serde::Deserialize;
-use swc_core::ecma::{ast::Program, visit::FoldWith};
-use swc_core::plugin::{
-    metadata::TransformPluginMetadataContextKind, plugin_transform,
-    proxies::TransformPluginProgramMetadata,
-};
+use swc_common::comments::Comments;
+use swc_core::ecma::visit::{Fold, VisitMut};
+#[cfg(feature = "plugin")]
+mod filter;
+mod options;
+#[cfg(feature = "plugin")]
+mod plugin;
+mod report;
 mod transformer;
 mod utils;
 mod visitor;
 
-#[derive(Deserialize)]
-#[serde(rename_all = "camelCase")]
-struct ReactRefreshOptions {
-    module_id: String,
-    skip_env_check: Option<bool>,
-}
-
-#[plugin_transform]
-fn swc_react_refresh_plugin(program: Program, data: TransformPluginProgramMetadata) -> Program {
-    let config = serde_json::from_str::<ReactRefreshOptions>(
-        &data
-            .get_transform_plugin_config()
-            .expect("failed to get plugin config for swc-plugin-react-refresh"),
-    )
-    .expect("invalid config for swc-plugin-react-refresh");
-
-    let env_name = &data
-        .get_context(&TransformPluginMetadataContextKind::Env)
-        .unwrap_or_default();
-    let is_dev = env_name.eq("development");
+pub use options::{Config, TransformOptions};
+pub use report::{ComponentReport, RefreshReport};
+pub use transformer::analyze;
 
-    if !is_dev && !config.skip_env_check.unwrap_or(false) {
-        panic!("swc-plugin-react-refresh transform should only be enabled in development environment.\n\
-        If you want to override this check, pass `skipEnvCheck` as plugin options.");
-    }
-
-    program.fold_with(&mut transformer::react_refresh(config.module_id))
+/// Returns the react-refresh transform.
+///
+/// Comments are used to read directives. (eg. `// @refresh reset`)
+pub fn react_refresh<C: Comments>(config: Config, comments: Option<C>) -> impl Fold + VisitMut {
+    transformer::react_refresh(config.module_id, config.options, comments)
 }
+
+#[cfg(test)]
+#[path = "./tests/config.rs"]
+mod config;
@@ -0,0 +1,49 @@
+use serde::Deserialize;
+
+/// Config of the react-refresh transform.
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Config {
+    /// Module id. (eg. generated id by bundler)
+    pub module_id: String,
+    #[serde(flatten)]
+    pub options: TransformOptions,
+}
+
+/// Options for the react-refresh transform.
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct TransformOptions {
+    /// Enable React Server Components support.
+    ///
+    /// Only modules that start with `"use client"` directive are transformed.
+    pub rsc: bool,
+    /// Batch registrations of the module with `beginModule` and `endModule`
+    /// instead of accepting each component.
+    pub batch_refresh: bool,
+    /// Keep identity of top-level contexts created by `createContext`
+    /// with `getOrCreateContext` across hot updates.
+    pub preserve_context: bool,
+    /// Sign components that are declared inside top-level functions.
+    ///
+    /// Nested components are not registered because they are not top-level bindings.
+    pub nested_components: bool,
+    /// Hooks that are treated as builtin hooks instead of the default list.
+    pub builtin_hooks: Option<Vec<String>>,
+    /// Hooks that are treated as builtin hooks in addition to the builtin hooks.
+    ///
+    /// Stable library hooks can be declared. (eg. `useWindowDimensions`)
+    pub additional_builtin_hooks: Vec<String>,
+    /// Regular expression that matches hook names.
+    ///
+    /// Defaults to `use` followed by an uppercase letter or `use` itself.
+    pub hook_pattern: Option<String>,
+    /// Modules that provide stable hooks in addition to `react` and `preact/hooks`.
+    ///
+    /// Hooks imported from these modules are treated as builtin hooks.
+    pub additional_hook_sources: Vec<String>,
+    /// Report hooks that are called conditionally, in loops or after an early return.
+    pub lint: bool,
+    /// Report components that can't be refreshed. (eg. anonymous, class components)
+    pub diagnostics: bool,
+}
@@ -0,0 +1,51 @@
+use crate::{filter, options::Config, react_refresh};
+use serde::Deserialize;
+use swc_core::ecma::{ast::Program, visit::VisitMutWith};
+use swc_core::plugin::{
+    metadata::TransformPluginMetadataContextKind, plugin_transform,
+    proxies::TransformPluginProgramMetadata,
+};
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ReactRefreshOptions {
+    skip_env_check: Option<bool>,
+    include: Option<Vec<String>>,
+    exclude: Option<Vec<String>>,
+    #[serde(flatten)]
+    config: Config,
+}
+
+#[plugin_transform]
+fn swc_react_refresh_plugin(mut program: Program, data: TransformPluginProgramMetadata) -> Program {
+    let config = serde_json::from_str::<ReactRefreshOptions>(
+        &data
+            .get_transform_plugin_config()
+            .expect("failed to get plugin config for swc-plugin-react-refresh"),
+    )
+    .expect("invalid config for swc-plugin-react-refresh");
+
+    let env_name = &data
+        .get_context(&TransformPluginMetadataContextKind::Env)
+        .unwrap_or_default();
+    let is_dev = env_name.eq("development");
+
+    if !is_dev && !config.skip_env_check.unwrap_or(false) {
+        panic!("swc-plugin-react-refresh transform should only be enabled in development environment.\n\
+        If you want to override this check, pass `skipEnvCheck` as plugin options.");
+    }
+
+    let filename = data.get_context(&TransformPluginMetadataContextKind::Filename);
+    let is_target = filename.is_none_or(|filename| {
+        filter::file_filter(&config.include, &config.exclude)
+            .expect("invalid `include` or `exclude` patterns for swc-plugin-react-refresh")
+            .is_match(&filename)
+    });
+
+    if !is_target {
+        return program;
+    }
+
+    program.visit_mut_with(&mut react_refresh(config.config, data.comments));
+    program
+}
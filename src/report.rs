@@ -0,0 +1,38 @@
+use serde::Serialize;
+
+/// Inventory of the module that is found by the react-refresh transform.
+#[derive(Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RefreshReport {
+    /// `true` when the module is not transformed.
+    /// (eg. `"use no refresh"`, server modules and already instrumented modules)
+    pub is_skipped: bool,
+    /// Components that are registered for Fast Refresh.
+    pub components: Vec<ComponentReport>,
+    /// Custom hooks that are called by components. (Components that call them are remounted)
+    pub custom_hooks: Vec<String>,
+    /// Identifiers that are never registered. (eg. class components, `// @refresh skip`)
+    pub black_list: Vec<String>,
+    /// Export names of the module.
+    pub exports: Vec<String>,
+    /// `true` when all of exports are components.
+    ///
+    /// If not, the update bubbles up to parent modules or fully reloads.
+    pub is_boundary: bool,
+}
+
+/// Component that is registered for Fast Refresh.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ComponentReport {
+    pub name: String,
+    /// Hooks that are called by the component in order.
+    pub hooks: Vec<String>,
+    /// Custom hooks that are called by the component.
+    pub custom_hooks: Vec<String>,
+    /// `true` when the signature forces to remount the component on every update.
+    /// (eg. custom hooks, `// @refresh reset` on the component that uses hooks)
+    pub force_reset: bool,
+    /// `true` when the component is `React.lazy` wrapper.
+    pub is_lazy: bool,
+}
@@ -0,0 +1,140 @@
+use super::analyze;
+use crate::report::RefreshReport;
+use serde_json::json;
+use swc_core::ecma::transforms::testing::Tester;
+
+/// Returns the report of the code as JSON.
+fn get_report(code: &str) -> serde_json::Value {
+    let syntax = swc_ecma_parser::Syntax::Es(swc_ecma_parser::EsConfig {
+        jsx: true,
+        ..Default::default()
+    });
+    let mut report = RefreshReport::default();
+
+    Tester::run(|tester| {
+        let module =
+            tester.with_parser("input.js", syntax, code, |parser| parser.parse_module())?;
+        report = analyze(&module, Default::default(), Some(tester.comments.clone()));
+        Ok(())
+    });
+
+    serde_json::to_value(report).unwrap()
+}
+
+#[test]
+fn analyze_components() {
+    let report = get_report(
+        r#"
+        import { useState as useS, lazy } from 'react';
+
+        export function Counter() {
+            const [count, setCount] = useS(0);
+            const theme = useTheme();
+            return <div>{count}</div>;
+        }
+
+        export const Page = lazy(() => import('./Page'));
+
+        // @refresh reset
+        export const Title = () => <h1 />;
+
+        // @refresh reset
+        export function Form() {
+            const [value, setValue] = useS('');
+            return <input value={value} />;
+        }
+
+        class Legacy extends React.Component {}
+        "#,
+    );
+
+    assert_eq!(
+        report,
+        json!({
+            "isSkipped": false,
+            "components": [
+                {
+                    "name": "Counter",
+                    "hooks": ["useState", "useTheme"],
+                    "customHooks": ["useTheme"],
+                    "forceReset": true,
+                    "isLazy": false,
+                },
+                {
+                    "name": "Page",
+                    "hooks": [],
+                    "customHooks": [],
+                    "forceReset": false,
+                    "isLazy": true,
+                },
+                {
+                    "name": "Title",
+                    "hooks": [],
+                    "customHooks": [],
+                    "forceReset": false,
+                    "isLazy": false,
+                },
+                {
+                    "name": "Form",
+                    "hooks": ["useState"],
+                    "customHooks": [],
+                    "forceReset": true,
+                    "isLazy": false,
+                },
+            ],
+            "customHooks": ["useTheme"],
+            "blackList": ["Legacy"],
+            "exports": ["Counter", "Page", "Title", "Form"],
+            "isBoundary": true,
+        })
+    );
+}
+
+#[test]
+fn analyze_non_boundary_module() {
+    let report = get_report(
+        r#"
+        export const Button = () => <button />;
+        export const formatLabel = (label) => label.trim();
+        "#,
+    );
+
+    assert_eq!(report["exports"], json!(["Button", "formatLabel"]));
+    assert_eq!(report["isBoundary"], json!(false));
+}
+
+#[test]
+fn analyze_skipped_module() {
+    let report = get_report(
+        r#"
+        "use no refresh";
+        export const Button = () => <button />;
+        "#,
+    );
+
+    assert_eq!(report["isSkipped"], json!(true));
+    assert_eq!(report["components"], json!([]));
+    assert_eq!(report["exports"], json!(["Button"]));
+    assert_eq!(report["isBoundary"], json!(false));
+}
+
+#[test]
+fn analyze_does_not_transform() {
+    let syntax = swc_ecma_parser::Syntax::Es(swc_ecma_parser::EsConfig {
+        jsx: true,
+        ..Default::default()
+    });
+
+    Tester::run(|tester| {
+        let module = tester.with_parser(
+            "input.js",
+            syntax,
+            "export function Component() { return <div />; }",
+            |parser| parser.parse_module(),
+        )?;
+        let original = module.clone();
+        analyze(&module, Default::default(), Some(tester.comments.clone()));
+        assert_eq!(module, original);
+        Ok(())
+    });
+}
@@ -0,0 +1,54 @@
+use super::react_refresh;
+use crate::options::TransformOptions;
+use swc_core::ecma::transforms::testing::test;
+
+test!(
+    swc_ecma_parser::Syntax::Es(swc_ecma_parser::EsConfig {
+        jsx: true,
+        ..Default::default()
+    }),
+    |tester| react_refresh(
+        String::from("test"),
+        TransformOptions {
+            batch_refresh: true,
+            ..Default::default()
+        },
+        Some(tester.comments.clone())
+    ),
+    batch_refresh_module,
+    // Input codes
+    r#"
+    export function BatchComponentA() {
+        const [number, setNumber] = useState(0);
+        return <div>{'Hello, World'}</div>;
+    }
+
+    export const BatchComponentB = () => {
+        return <div>{'Hello, World'}</div>;
+    };
+    "#,
+    // Output
+    r#"
+    var __prevRefreshReg = global.$RefreshReg$;
+    var __prevRefreshSig = global.$RefreshSig$;
+    global.$RefreshReg$ = global.$RefreshRuntime$.getRegisterFunction();
+    global.$RefreshSig$ = global.$RefreshRuntime$.getCreateSignatureFunction();
+    var __s = global.$RefreshSig$();
+    export function BatchComponentA() {
+        __s();
+        const [number, setNumber] = useState(0);
+        return <div>{'Hello, World'}</div>;
+    }
+    export const BatchComponentB = () => {
+        return <div>{'Hello, World'}</div>;
+    };
+    global.$RefreshRuntime$.beginModule("test");
+    __s(BatchComponentA, "test:BatchComponentA", false);
+    global.$RefreshReg$(BatchComponentA, "BatchComponentA");
+    global.$RefreshReg$(BatchComponentB, "BatchComponentB");
+    global.$RefreshRuntime$.registerModule?.("test", { exports: ["BatchComponentA", "BatchComponentB"], components: ["BatchComponentA", "BatchComponentB"], isBoundary: true });
+    global.$RefreshRuntime$.endModule("test");
+    global.$RefreshReg$ = __prevRefreshReg;
+    global.$RefreshSig$ = __prevRefreshSig;
+    "#
+);
@@ -1,12 +1,32 @@
-use super::react_refresh;
-use swc_core::ecma::transforms::testing::test;
+use super::{react_refresh, ReactRefreshRuntime};
+use swc_core::{
+    common::chain,
+    ecma::{
+        ast::Module,
+        transforms::testing::test,
+        visit::{as_folder, VisitMut, VisitMutWith},
+    },
+};
+
+/// Visit only the module items with the provided visitor.
+struct ModuleItemsVisitor<V: VisitMut>(V);
+
+impl<V: VisitMut> VisitMut for ModuleItemsVisitor<V> {
+    fn visit_mut_module(&mut self, module: &mut Module) {
+        module.body.visit_mut_with(&mut self.0);
+    }
+}
 
 test!(
     swc_ecma_parser::Syntax::Es(swc_ecma_parser::EsConfig {
         jsx: true,
         ..Default::default()
     }),
-    |_| react_refresh(String::from("test")),
+    |tester| react_refresh(
+        String::from("test"),
+        Default::default(),
+        Some(tester.comments.clone())
+    ),
     non_component,
     // Input codes
     r#"
@@ -27,7 +47,11 @@ test!(
         jsx: true,
         ..Default::default()
     }),
-    |_| react_refresh(String::from("test")),
+    |tester| react_refresh(
+        String::from("test"),
+        Default::default(),
+        Some(tester.comments.clone())
+    ),
     multiple_components,
     // Input codes
     r#"
@@ -54,6 +78,7 @@ test!(
     global.$RefreshRuntime$.getContext(MultipleA).accept();
     global.$RefreshReg$(MultipleB, "MultipleB");
     global.$RefreshRuntime$.getContext(MultipleB).accept();
+    global.$RefreshRuntime$.registerModule?.("test", { exports: ["MultipleA", "MultipleB"], components: ["MultipleA", "MultipleB"], isBoundary: true });
     global.$RefreshReg$ = __prevRefreshReg;
     global.$RefreshSig$ = __prevRefreshSig;
     "#
@@ -64,7 +89,11 @@ test!(
         jsx: true,
         ..Default::default()
     }),
-    |_| react_refresh(String::from("test")),
+    |tester| react_refresh(
+        String::from("test"),
+        Default::default(),
+        Some(tester.comments.clone())
+    ),
     invalid_hook_call_in_component,
     // Input codes
     r#"
@@ -85,6 +114,7 @@ test!(
     };
     global.$RefreshReg$(NotHook, "NotHook");
     global.$RefreshRuntime$.getContext(NotHook).accept();
+    global.$RefreshRuntime$.registerModule?.("test", { exports: ["NotHook"], components: ["NotHook"], isBoundary: true });
     global.$RefreshReg$ = __prevRefreshReg;
     global.$RefreshSig$ = __prevRefreshSig;
     "#
@@ -95,7 +125,11 @@ test!(
         jsx: true,
         ..Default::default()
     }),
-    |_| react_refresh(String::from("test")),
+    |tester| react_refresh(
+        String::from("test"),
+        Default::default(),
+        Some(tester.comments.clone())
+    ),
     multiple_variable_declares,
     // Input codes
     r#"
@@ -110,3 +144,162 @@ test!(
     };
     "#
 );
+
+test!(
+    swc_ecma_parser::Syntax::Es(swc_ecma_parser::EsConfig {
+        jsx: true,
+        ..Default::default()
+    }),
+    |tester| chain!(
+        react_refresh(
+            String::from("test"),
+            Default::default(),
+            Some(tester.comments.clone())
+        ),
+        react_refresh(
+            String::from("test"),
+            Default::default(),
+            Some(tester.comments.clone())
+        )
+    ),
+    already_instrumented_module,
+    // Input codes
+    r#"
+    export function Component() {
+        const [number, setNumber] = useState(0);
+        return <div>{'Hello, World'}</div>;
+    }
+    "#,
+    // Output
+    r#"
+    var __prevRefreshReg = global.$RefreshReg$;
+    var __prevRefreshSig = global.$RefreshSig$;
+    global.$RefreshReg$ = global.$RefreshRuntime$.getRegisterFunction();
+    global.$RefreshSig$ = global.$RefreshRuntime$.getCreateSignatureFunction();
+    var __s = global.$RefreshSig$();
+    export function Component() {
+        __s();
+        const [number, setNumber] = useState(0);
+        return <div>{'Hello, World'}</div>;
+    }
+    __s(Component, "test:Component", false);
+    global.$RefreshReg$(Component, "Component");
+    global.$RefreshRuntime$.getContext(Component).accept();
+    global.$RefreshRuntime$.registerModule?.("test", { exports: ["Component"], components: ["Component"], isBoundary: true });
+    global.$RefreshReg$ = __prevRefreshReg;
+    global.$RefreshSig$ = __prevRefreshSig;
+    "#
+);
+
+test!(
+    swc_ecma_parser::Syntax::Es(swc_ecma_parser::EsConfig {
+        jsx: true,
+        ..Default::default()
+    }),
+    |tester| react_refresh(
+        String::from("test"),
+        Default::default(),
+        Some(tester.comments.clone())
+    ),
+    mixed_exports_module,
+    // Input codes
+    r#"
+    export const TIMEOUT = 5000;
+
+    export function MixedExportsComponent() {
+        return <div>{'Hello, World'}</div>;
+    }
+
+    export * from './constants';
+    "#,
+    // Output
+    r#"
+    var __prevRefreshReg = global.$RefreshReg$;
+    var __prevRefreshSig = global.$RefreshSig$;
+    global.$RefreshReg$ = global.$RefreshRuntime$.getRegisterFunction();
+    export const TIMEOUT = 5000;
+    export function MixedExportsComponent() {
+        return <div>{'Hello, World'}</div>;
+    }
+    export * from './constants';
+    global.$RefreshReg$(MixedExportsComponent, "MixedExportsComponent");
+    global.$RefreshRuntime$.getContext(MixedExportsComponent).accept();
+    global.$RefreshRuntime$.registerModule?.("test", { exports: ["TIMEOUT", "MixedExportsComponent", "*"], components: ["MixedExportsComponent"], isBoundary: false });
+    global.$RefreshReg$ = __prevRefreshReg;
+    global.$RefreshSig$ = __prevRefreshSig;
+    "#
+);
+
+test!(
+    swc_ecma_parser::Syntax::Es(swc_ecma_parser::EsConfig {
+        jsx: true,
+        ..Default::default()
+    }),
+    |tester| react_refresh(
+        String::from("test"),
+        Default::default(),
+        Some(tester.comments.clone())
+    ),
+    hoc_default_export_module,
+    // Input codes
+    r#"
+    function Counter() {
+        return <div>{'Hello, World'}</div>;
+    }
+
+    export const Title = () => <h1>{'Title'}</h1>;
+
+    export default memo(Counter);
+    "#,
+    // Output
+    r#"
+    var __prevRefreshReg = global.$RefreshReg$;
+    var __prevRefreshSig = global.$RefreshSig$;
+    global.$RefreshReg$ = global.$RefreshRuntime$.getRegisterFunction();
+    function Counter() {
+        return <div>{'Hello, World'}</div>;
+    }
+    export const Title = () => <h1>{'Title'}</h1>;
+    export default memo(Counter);
+    global.$RefreshReg$(Counter, "Counter");
+    global.$RefreshRuntime$.getContext(Counter).accept();
+    global.$RefreshReg$(Title, "Title");
+    global.$RefreshRuntime$.getContext(Title).accept();
+    global.$RefreshRuntime$.registerModule?.("test", { exports: ["Title", "default"], components: ["Counter", "Title"], isBoundary: true });
+    global.$RefreshReg$ = __prevRefreshReg;
+    global.$RefreshSig$ = __prevRefreshSig;
+    "#
+);
+
+test!(
+    swc_ecma_parser::Syntax::Es(swc_ecma_parser::EsConfig {
+        jsx: true,
+        ..Default::default()
+    }),
+    |tester| as_folder(ModuleItemsVisitor(ReactRefreshRuntime::default(
+        String::from("test"),
+        Default::default(),
+        Some(tester.comments.clone())
+    ))),
+    visit_module_items,
+    // Input codes
+    r#"
+    export function Component() {
+        return <div>{'Hello, World'}</div>;
+    }
+    "#,
+    // Output
+    r#"
+    var __prevRefreshReg = global.$RefreshReg$;
+    var __prevRefreshSig = global.$RefreshSig$;
+    global.$RefreshReg$ = global.$RefreshRuntime$.getRegisterFunction();
+    export function Component() {
+        return <div>{'Hello, World'}</div>;
+    }
+    global.$RefreshReg$(Component, "Component");
+    global.$RefreshRuntime$.getContext(Component).accept();
+    global.$RefreshRuntime$.registerModule?.("test", { exports: ["Component"], components: ["Component"], isBoundary: true });
+    global.$RefreshReg$ = __prevRefreshReg;
+    global.$RefreshSig$ = __prevRefreshSig;
+    "#
+);
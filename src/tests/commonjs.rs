@@ -0,0 +1,231 @@
+use super::react_refresh;
+use swc_core::ecma::{
+    ast::{Module, ModuleItem, Script},
+    transforms::testing::{test, Tester},
+    visit::FoldWith,
+};
+
+test!(
+    swc_ecma_parser::Syntax::Es(swc_ecma_parser::EsConfig {
+        jsx: true,
+        ..Default::default()
+    }),
+    |tester| react_refresh(
+        String::from("test"),
+        Default::default(),
+        Some(tester.comments.clone())
+    ),
+    commonjs_module_exports_component,
+    // Input codes
+    r#"
+    module.exports = function ModuleExportsComponent() {
+        return <div>{'Hello, World'}</div>;
+    };
+    "#,
+    // Output
+    r#"
+    var __prevRefreshReg = global.$RefreshReg$;
+    var __prevRefreshSig = global.$RefreshSig$;
+    global.$RefreshReg$ = global.$RefreshRuntime$.getRegisterFunction();
+    module.exports = function ModuleExportsComponent() {
+        return <div>{'Hello, World'}</div>;
+    };
+    global.$RefreshReg$(module.exports, "ModuleExportsComponent");
+    global.$RefreshRuntime$.getContext(module.exports).accept();
+    global.$RefreshRuntime$.registerModule?.("test", { exports: ["default"], components: ["ModuleExportsComponent"], isBoundary: true });
+    global.$RefreshReg$ = __prevRefreshReg;
+    global.$RefreshSig$ = __prevRefreshSig;
+    "#
+);
+
+test!(
+    swc_ecma_parser::Syntax::Es(swc_ecma_parser::EsConfig {
+        jsx: true,
+        ..Default::default()
+    }),
+    |tester| react_refresh(
+        String::from("test"),
+        Default::default(),
+        Some(tester.comments.clone())
+    ),
+    commonjs_named_exports_component,
+    // Input codes
+    r#"
+    exports.NamedExportsComponent = () => {
+        const [number, setNumber] = useState(0);
+        return <div>{'Hello, World'}</div>;
+    };
+
+    module.exports.ModuleNamedExportsComponent = () => {
+        return <div>{'Hello, World'}</div>;
+    };
+    "#,
+    // Output
+    r#"
+    var __prevRefreshReg = global.$RefreshReg$;
+    var __prevRefreshSig = global.$RefreshSig$;
+    global.$RefreshReg$ = global.$RefreshRuntime$.getRegisterFunction();
+    global.$RefreshSig$ = global.$RefreshRuntime$.getCreateSignatureFunction();
+    var __s = global.$RefreshSig$();
+    exports.NamedExportsComponent = () => {
+        __s();
+        const [number, setNumber] = useState(0);
+        return <div>{'Hello, World'}</div>;
+    };
+    module.exports.ModuleNamedExportsComponent = () => {
+        return <div>{'Hello, World'}</div>;
+    };
+    __s(exports.NamedExportsComponent, "test:NamedExportsComponent", false);
+    global.$RefreshReg$(exports.NamedExportsComponent, "NamedExportsComponent");
+    global.$RefreshRuntime$.getContext(exports.NamedExportsComponent).accept();
+    global.$RefreshReg$(module.exports.ModuleNamedExportsComponent, "ModuleNamedExportsComponent");
+    global.$RefreshRuntime$.getContext(module.exports.ModuleNamedExportsComponent).accept();
+    global.$RefreshRuntime$.registerModule?.("test", { exports: ["NamedExportsComponent", "ModuleNamedExportsComponent"], components: ["NamedExportsComponent", "ModuleNamedExportsComponent"], isBoundary: true });
+    global.$RefreshReg$ = __prevRefreshReg;
+    global.$RefreshSig$ = __prevRefreshSig;
+    "#
+);
+
+test!(
+    swc_ecma_parser::Syntax::Es(swc_ecma_parser::EsConfig {
+        jsx: true,
+        ..Default::default()
+    }),
+    |tester| react_refresh(
+        String::from("test"),
+        Default::default(),
+        Some(tester.comments.clone())
+    ),
+    commonjs_non_component_exports,
+    // Input codes
+    r#"
+    exports.TIMEOUT = 5000;
+    exports.helper = () => {};
+    module.exports = () => {};
+    "#,
+    // Output
+    r#"
+    exports.TIMEOUT = 5000;
+    exports.helper = () => {};
+    module.exports = () => {};
+    "#
+);
+
+test!(
+    swc_ecma_parser::Syntax::Es(swc_ecma_parser::EsConfig {
+        jsx: true,
+        ..Default::default()
+    }),
+    |tester| react_refresh(
+        String::from("test"),
+        Default::default(),
+        Some(tester.comments.clone())
+    ),
+    commonjs_define_property_exports,
+    // Input codes
+    r#"
+    Object.defineProperty(exports, "__esModule", { value: true });
+    Object.defineProperty(exports, "DefinedComponent", {
+        enumerable: true,
+        get: function () {
+            return DefinedComponent;
+        }
+    });
+    Object.defineProperty(exports, "helper", {
+        enumerable: true,
+        get: () => helper
+    });
+    function DefinedComponent() {
+        return <div>{'Hello, World'}</div>;
+    }
+    function helper() {}
+    "#,
+    // Output
+    r#"
+    var __prevRefreshReg = global.$RefreshReg$;
+    var __prevRefreshSig = global.$RefreshSig$;
+    global.$RefreshReg$ = global.$RefreshRuntime$.getRegisterFunction();
+    Object.defineProperty(exports, "__esModule", { value: true });
+    Object.defineProperty(exports, "DefinedComponent", {
+        enumerable: true,
+        get: function () {
+            return DefinedComponent;
+        }
+    });
+    Object.defineProperty(exports, "helper", {
+        enumerable: true,
+        get: () => helper
+    });
+    function DefinedComponent() {
+        return <div>{'Hello, World'}</div>;
+    }
+    function helper() {}
+    global.$RefreshReg$(DefinedComponent, "DefinedComponent");
+    global.$RefreshRuntime$.getContext(DefinedComponent).accept();
+    global.$RefreshRuntime$.registerModule?.("test", { exports: ["DefinedComponent", "helper"], components: ["DefinedComponent"], isBoundary: false });
+    global.$RefreshReg$ = __prevRefreshReg;
+    global.$RefreshSig$ = __prevRefreshSig;
+    "#
+);
+
+#[test]
+fn commonjs_script() {
+    let syntax = swc_ecma_parser::Syntax::Es(swc_ecma_parser::EsConfig {
+        jsx: true,
+        ..Default::default()
+    });
+    let to_module = |script: Script| Module {
+        span: script.span,
+        body: script.body.into_iter().map(ModuleItem::Stmt).collect(),
+        shebang: script.shebang,
+    };
+
+    Tester::run(|tester| {
+        let actual = tester
+            .with_parser(
+                "input.js",
+                syntax,
+                r#"
+                'use strict';
+                var React = require('react');
+                function ScriptComponent() {
+                    return <div>{'Hello, World'}</div>;
+                }
+                module.exports = ScriptComponent;
+                "#,
+                |parser| parser.parse_script(),
+            )?
+            .fold_with(&mut react_refresh(
+                String::from("test"),
+                Default::default(),
+                Some(tester.comments.clone()),
+            ));
+        let expected = tester.with_parser(
+            "output.js",
+            syntax,
+            r#"
+            'use strict';
+            var __prevRefreshReg = global.$RefreshReg$;
+            var __prevRefreshSig = global.$RefreshSig$;
+            global.$RefreshReg$ = global.$RefreshRuntime$.getRegisterFunction();
+            var React = require('react');
+            function ScriptComponent() {
+                return <div>{'Hello, World'}</div>;
+            }
+            module.exports = ScriptComponent;
+            global.$RefreshReg$(ScriptComponent, "ScriptComponent");
+            global.$RefreshRuntime$.getContext(ScriptComponent).accept();
+            global.$RefreshRuntime$.registerModule?.("test", { exports: ["default"], components: ["ScriptComponent"], isBoundary: true });
+            global.$RefreshReg$ = __prevRefreshReg;
+            global.$RefreshSig$ = __prevRefreshSig;
+            "#,
+            |parser| parser.parse_script(),
+        )?;
+
+        assert_eq!(
+            tester.print(&to_module(actual), &Default::default()),
+            tester.print(&to_module(expected), &Default::default())
+        );
+        Ok(())
+    });
+}
@@ -6,7 +6,11 @@ test!(
         jsx: true,
         ..Default::default()
     }),
-    |_| react_refresh(String::from("test")),
+    |tester| react_refresh(
+        String::from("test"),
+        Default::default(),
+        Some(tester.comments.clone())
+    ),
     arrow_function_component,
     // Input codes
     r#"
@@ -24,6 +28,7 @@ test!(
     };
     global.$RefreshReg$(ArrowComponent, "ArrowComponent");
     global.$RefreshRuntime$.getContext(ArrowComponent).accept();
+    global.$RefreshRuntime$.registerModule?.("test", { exports: [], components: ["ArrowComponent"], isBoundary: false });
     global.$RefreshReg$ = __prevRefreshReg;
     global.$RefreshSig$ = __prevRefreshSig;
     "#
@@ -34,7 +39,11 @@ test!(
         jsx: true,
         ..Default::default()
     }),
-    |_| react_refresh(String::from("test")),
+    |tester| react_refresh(
+        String::from("test"),
+        Default::default(),
+        Some(tester.comments.clone())
+    ),
     arrow_function_component_default_export,
     // Input codes
     r#"
@@ -55,7 +64,11 @@ test!(
         jsx: true,
         ..Default::default()
     }),
-    |_| react_refresh(String::from("test")),
+    |tester| react_refresh(
+        String::from("test"),
+        Default::default(),
+        Some(tester.comments.clone())
+    ),
     arrow_function_component_default_export_from_var,
     // Input codes
     r#"
@@ -76,6 +89,7 @@ test!(
     export default ArrowComponentDefaultFromVar;
     global.$RefreshReg$(ArrowComponentDefaultFromVar, "ArrowComponentDefaultFromVar");
     global.$RefreshRuntime$.getContext(ArrowComponentDefaultFromVar).accept();
+    global.$RefreshRuntime$.registerModule?.("test", { exports: ["default"], components: ["ArrowComponentDefaultFromVar"], isBoundary: true });
     global.$RefreshReg$ = __prevRefreshReg;
     global.$RefreshSig$ = __prevRefreshSig;
     "#
@@ -86,7 +100,11 @@ test!(
         jsx: true,
         ..Default::default()
     }),
-    |_| react_refresh(String::from("test")),
+    |tester| react_refresh(
+        String::from("test"),
+        Default::default(),
+        Some(tester.comments.clone())
+    ),
     arrow_function_component_named_export,
     // Input codes
     r#"
@@ -107,6 +125,7 @@ test!(
     export { ArrowComponentNamedExport };
     global.$RefreshReg$(ArrowComponentNamedExport, "ArrowComponentNamedExport");
     global.$RefreshRuntime$.getContext(ArrowComponentNamedExport).accept();
+    global.$RefreshRuntime$.registerModule?.("test", { exports: ["ArrowComponentNamedExport"], components: ["ArrowComponentNamedExport"], isBoundary: true });
     global.$RefreshReg$ = __prevRefreshReg;
     global.$RefreshSig$ = __prevRefreshSig;
     "#
@@ -117,7 +136,11 @@ test!(
         jsx: true,
         ..Default::default()
     }),
-    |_| react_refresh(String::from("test")),
+    |tester| react_refresh(
+        String::from("test"),
+        Default::default(),
+        Some(tester.comments.clone())
+    ),
     arrow_function_component_named_export_as_rename,
     // Input codes
     r#"
@@ -138,6 +161,7 @@ test!(
     export { ArrowComponentNamedExportAs as Rename };
     global.$RefreshReg$(ArrowComponentNamedExportAs, "ArrowComponentNamedExportAs");
     global.$RefreshRuntime$.getContext(ArrowComponentNamedExportAs).accept();
+    global.$RefreshRuntime$.registerModule?.("test", { exports: ["Rename"], components: ["ArrowComponentNamedExportAs"], isBoundary: true });
     global.$RefreshReg$ = __prevRefreshReg;
     global.$RefreshSig$ = __prevRefreshSig;
     "#
@@ -148,7 +172,11 @@ test!(
         jsx: true,
         ..Default::default()
     }),
-    |_| react_refresh(String::from("test")),
+    |tester| react_refresh(
+        String::from("test"),
+        Default::default(),
+        Some(tester.comments.clone())
+    ),
     arrow_function_component_named_export_with_declare,
     // Input codes
     r#"
@@ -166,6 +194,7 @@ test!(
     };
     global.$RefreshReg$(ArrowComponentNamedExportDeclare, "ArrowComponentNamedExportDeclare");
     global.$RefreshRuntime$.getContext(ArrowComponentNamedExportDeclare).accept();
+    global.$RefreshRuntime$.registerModule?.("test", { exports: ["ArrowComponentNamedExportDeclare"], components: ["ArrowComponentNamedExportDeclare"], isBoundary: true });
     global.$RefreshReg$ = __prevRefreshReg;
     global.$RefreshSig$ = __prevRefreshSig;
     "#
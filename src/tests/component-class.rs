@@ -6,7 +6,11 @@ test!(
         jsx: true,
         ..Default::default()
     }),
-    |_| react_refresh(String::from("test")),
+    |tester| react_refresh(
+        String::from("test"),
+        Default::default(),
+        Some(tester.comments.clone())
+    ),
     class_component,
     // Input codes
     r#"
@@ -6,7 +6,11 @@ test!(
         jsx: true,
         ..Default::default()
     }),
-    |_| react_refresh(String::from("test")),
+    |tester| react_refresh(
+        String::from("test"),
+        Default::default(),
+        Some(tester.comments.clone())
+    ),
     function_component,
     // Input codes
     r#"
@@ -24,6 +28,7 @@ test!(
     };
     global.$RefreshReg$(Component, "Component");
     global.$RefreshRuntime$.getContext(Component).accept();
+    global.$RefreshRuntime$.registerModule?.("test", { exports: [], components: ["Component"], isBoundary: false });
     global.$RefreshReg$ = __prevRefreshReg;
     global.$RefreshSig$ = __prevRefreshSig;
     "#
@@ -34,7 +39,11 @@ test!(
         jsx: true,
         ..Default::default()
     }),
-    |_| react_refresh(String::from("test")),
+    |tester| react_refresh(
+        String::from("test"),
+        Default::default(),
+        Some(tester.comments.clone())
+    ),
     function_component_default_export,
     // Input codes
     r#"
@@ -55,7 +64,11 @@ test!(
         jsx: true,
         ..Default::default()
     }),
-    |_| react_refresh(String::from("test")),
+    |tester| react_refresh(
+        String::from("test"),
+        Default::default(),
+        Some(tester.comments.clone())
+    ),
     function_component_default_export_with_name,
     // Input codes
     r#"
@@ -73,6 +86,7 @@ test!(
     };
     global.$RefreshReg$(ComponentDefault, "ComponentDefault");
     global.$RefreshRuntime$.getContext(ComponentDefault).accept();
+    global.$RefreshRuntime$.registerModule?.("test", { exports: ["default"], components: ["ComponentDefault"], isBoundary: true });
     global.$RefreshReg$ = __prevRefreshReg;
     global.$RefreshSig$ = __prevRefreshSig;
     "#
@@ -83,7 +97,11 @@ test!(
         jsx: true,
         ..Default::default()
     }),
-    |_| react_refresh(String::from("test")),
+    |tester| react_refresh(
+        String::from("test"),
+        Default::default(),
+        Some(tester.comments.clone())
+    ),
     function_component_default_export_from_var,
     // Input codes
     r#"
@@ -104,6 +122,7 @@ test!(
     export default ComponentDefaultFromVar;
     global.$RefreshReg$(ComponentDefaultFromVar, "ComponentDefaultFromVar");
     global.$RefreshRuntime$.getContext(ComponentDefaultFromVar).accept();
+    global.$RefreshRuntime$.registerModule?.("test", { exports: ["default"], components: ["ComponentDefaultFromVar"], isBoundary: true });
     global.$RefreshReg$ = __prevRefreshReg;
     global.$RefreshSig$ = __prevRefreshSig;
     "#
@@ -114,7 +133,11 @@ test!(
         jsx: true,
         ..Default::default()
     }),
-    |_| react_refresh(String::from("test")),
+    |tester| react_refresh(
+        String::from("test"),
+        Default::default(),
+        Some(tester.comments.clone())
+    ),
     function_component_named_export,
     // Input codes
     r#"
@@ -135,6 +158,7 @@ test!(
     export { ComponentNamedExport };
     global.$RefreshReg$(ComponentNamedExport, "ComponentNamedExport");
     global.$RefreshRuntime$.getContext(ComponentNamedExport).accept();
+    global.$RefreshRuntime$.registerModule?.("test", { exports: ["ComponentNamedExport"], components: ["ComponentNamedExport"], isBoundary: true });
     global.$RefreshReg$ = __prevRefreshReg;
     global.$RefreshSig$ = __prevRefreshSig;
     "#
@@ -145,7 +169,11 @@ test!(
         jsx: true,
         ..Default::default()
     }),
-    |_| react_refresh(String::from("test")),
+    |tester| react_refresh(
+        String::from("test"),
+        Default::default(),
+        Some(tester.comments.clone())
+    ),
     function_component_named_export_as_rename,
     // Input codes
     r#"
@@ -166,6 +194,7 @@ test!(
     export { ComponentNamedExportAs as Rename };
     global.$RefreshReg$(ComponentNamedExportAs, "ComponentNamedExportAs");
     global.$RefreshRuntime$.getContext(ComponentNamedExportAs).accept();
+    global.$RefreshRuntime$.registerModule?.("test", { exports: ["Rename"], components: ["ComponentNamedExportAs"], isBoundary: true });
     global.$RefreshReg$ = __prevRefreshReg;
     global.$RefreshSig$ = __prevRefreshSig;
     "#
@@ -176,7 +205,11 @@ test!(
         jsx: true,
         ..Default::default()
     }),
-    |_| react_refresh(String::from("test")),
+    |tester| react_refresh(
+        String::from("test"),
+        Default::default(),
+        Some(tester.comments.clone())
+    ),
     function_component_named_export_with_declare,
     // Input codes
     r#"
@@ -194,6 +227,7 @@ test!(
     };
     global.$RefreshReg$(ComponentNamedExportDeclare, "ComponentNamedExportDeclare");
     global.$RefreshRuntime$.getContext(ComponentNamedExportDeclare).accept();
+    global.$RefreshRuntime$.registerModule?.("test", { exports: ["ComponentNamedExportDeclare"], components: ["ComponentNamedExportDeclare"], isBoundary: true });
     global.$RefreshReg$ = __prevRefreshReg;
     global.$RefreshSig$ = __prevRefreshSig;
     "#
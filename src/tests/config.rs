@@ -0,0 +1,35 @@
+use super::{react_refresh, Config};
+use swc_core::ecma::transforms::testing::test;
+
+test!(
+    swc_ecma_parser::Syntax::Es(swc_ecma_parser::EsConfig {
+        jsx: true,
+        ..Default::default()
+    }),
+    |tester| react_refresh(
+        serde_json::from_str::<Config>(r#"{ "moduleId": "app", "batchRefresh": true }"#).unwrap(),
+        Some(tester.comments.clone())
+    ),
+    config_from_json,
+    // Input codes
+    r#"
+    export function Component() {
+        return <div>{'Hello, World'}</div>;
+    }
+    "#,
+    // Output
+    r#"
+    var __prevRefreshReg = global.$RefreshReg$;
+    var __prevRefreshSig = global.$RefreshSig$;
+    global.$RefreshReg$ = global.$RefreshRuntime$.getRegisterFunction();
+    export function Component() {
+        return <div>{'Hello, World'}</div>;
+    }
+    global.$RefreshRuntime$.beginModule("app");
+    global.$RefreshReg$(Component, "Component");
+    global.$RefreshRuntime$.registerModule?.("app", { exports: ["Component"], components: ["Component"], isBoundary: true });
+    global.$RefreshRuntime$.endModule("app");
+    global.$RefreshReg$ = __prevRefreshReg;
+    global.$RefreshSig$ = __prevRefreshSig;
+    "#
+);
@@ -0,0 +1,108 @@
+use super::react_refresh;
+use crate::options::TransformOptions;
+use swc_core::{common::chain, ecma::transforms::testing::test};
+
+test!(
+    swc_ecma_parser::Syntax::Es(swc_ecma_parser::EsConfig {
+        jsx: true,
+        ..Default::default()
+    }),
+    |tester| react_refresh(
+        String::from("test"),
+        TransformOptions {
+            preserve_context: true,
+            ..Default::default()
+        },
+        Some(tester.comments.clone())
+    ),
+    preserve_context,
+    // Input codes
+    r#"
+    import React, { createContext } from 'react';
+
+    const ThemeContext = createContext('light');
+    export const UserContext = React.createContext(null);
+
+    export function ContextComponent() {
+        return <div>{'Hello, World'}</div>;
+    }
+    "#,
+    // Output
+    r#"
+    var __prevRefreshReg = global.$RefreshReg$;
+    var __prevRefreshSig = global.$RefreshSig$;
+    global.$RefreshReg$ = global.$RefreshRuntime$.getRegisterFunction();
+    import React, { createContext } from 'react';
+    const ThemeContext = global.$RefreshRuntime$.getOrCreateContext("test:ThemeContext", () => createContext('light'));
+    export const UserContext = global.$RefreshRuntime$.getOrCreateContext("test:UserContext", () => React.createContext(null));
+    export function ContextComponent() {
+        return <div>{'Hello, World'}</div>;
+    }
+    global.$RefreshReg$(ContextComponent, "ContextComponent");
+    global.$RefreshRuntime$.getContext(ContextComponent).accept();
+    global.$RefreshRuntime$.registerModule?.("test", { exports: ["UserContext", "ContextComponent"], components: ["ContextComponent"], isBoundary: false });
+    global.$RefreshReg$ = __prevRefreshReg;
+    global.$RefreshSig$ = __prevRefreshSig;
+    "#
+);
+
+test!(
+    swc_ecma_parser::Syntax::Es(swc_ecma_parser::EsConfig {
+        jsx: true,
+        ..Default::default()
+    }),
+    |tester| react_refresh(
+        String::from("test"),
+        Default::default(),
+        Some(tester.comments.clone())
+    ),
+    preserve_context_disabled,
+    // Input codes
+    r#"
+    import { createContext } from 'react';
+
+    export const ThemeContext = createContext('light');
+    "#,
+    // Output
+    r#"
+    import { createContext } from 'react';
+    export const ThemeContext = createContext('light');
+    "#
+);
+
+test!(
+    swc_ecma_parser::Syntax::Es(swc_ecma_parser::EsConfig {
+        jsx: true,
+        ..Default::default()
+    }),
+    |tester| chain!(
+        react_refresh(
+            String::from("test"),
+            TransformOptions {
+                preserve_context: true,
+                ..Default::default()
+            },
+            Some(tester.comments.clone())
+        ),
+        react_refresh(
+            String::from("test"),
+            TransformOptions {
+                preserve_context: true,
+                ..Default::default()
+            },
+            Some(tester.comments.clone())
+        )
+    ),
+    preserve_context_twice,
+    // Input codes
+    r#"
+    import { createContext } from 'react';
+
+    const ThemeContext = createContext('light');
+    "#,
+    // Output
+    r#"
+    import { createContext } from 'react';
+    const ThemeContext = global.$RefreshRuntime$.getOrCreateContext("test:ThemeContext", () => createContext('light'));
+    "#
+);
@@ -0,0 +1,117 @@
+use super::warnings::get_warnings;
+use crate::options::TransformOptions;
+
+/// Returns options that enable or disable `diagnostics`.
+fn diagnostics_options(diagnostics: bool) -> TransformOptions {
+    TransformOptions {
+        diagnostics,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn diagnostics_anonymous_default_export() {
+    assert_eq!(
+        get_warnings("export default () => <div />;", diagnostics_options(true)),
+        vec!["Anonymous default export can't be refreshed. Name the component to preserve its state."]
+    );
+    assert_eq!(
+        get_warnings("export default function () { return <div />; }", diagnostics_options(true)),
+        vec!["Anonymous default export can't be refreshed. Name the component to preserve its state."]
+    );
+}
+
+#[test]
+fn diagnostics_class_components() {
+    let warnings = get_warnings(
+        r#"
+        class Counter extends React.Component {
+            render() {
+                return <div />;
+            }
+        }
+        export class Modal extends Component {}
+        class List extends PureComponent {}
+        class Store {}
+        class ApiError extends Error {}
+        class Cache extends Storage.Base {}
+        "#,
+        diagnostics_options(true),
+    );
+
+    assert_eq!(
+        warnings,
+        vec![
+            "Class component \"Counter\" can't be refreshed. It is remounted on every update.",
+            "Class component \"Modal\" can't be refreshed. It is remounted on every update.",
+            "Class component \"List\" can't be refreshed. It is remounted on every update.",
+        ]
+    );
+}
+
+#[test]
+fn diagnostics_multi_declarator_components() {
+    let warnings = get_warnings(
+        r#"
+        var Header = () => <header />, Footer = function () { return <footer />; }, count = 0;
+        const Title = () => <h1 />;
+        export const Nav = () => <nav />, Aside = () => <aside />;
+        "#,
+        diagnostics_options(true),
+    );
+
+    assert_eq!(
+        warnings,
+        vec![
+            "Component \"Header\" can't be refreshed because it is declared with other variables. Declare it in a separate statement.",
+            "Component \"Footer\" can't be refreshed because it is declared with other variables. Declare it in a separate statement.",
+        ]
+    );
+}
+
+#[test]
+fn diagnostics_non_component_exports() {
+    let warnings = get_warnings(
+        r#"
+        export const Button = () => <button />;
+        export const formatLabel = (label) => label.trim();
+        export function __refreshDispose() {}
+        "#,
+        diagnostics_options(true),
+    );
+
+    assert_eq!(
+        warnings,
+        vec!["Export \"formatLabel\" is not a component. Modules that export both components and non-components can't be refreshed and cause a full reload."]
+    );
+}
+
+#[test]
+fn diagnostics_boundary_module() {
+    let warnings = get_warnings(
+        r#"
+        export const Button = () => <button />;
+        export function Input() {
+            return <input />;
+        }
+        "#,
+        diagnostics_options(true),
+    );
+
+    assert!(warnings.is_empty());
+}
+
+#[test]
+fn diagnostics_disabled() {
+    let warnings = get_warnings(
+        r#"
+        export default () => <div />;
+        class Counter extends React.Component {}
+        export const Button = () => <button />;
+        export const formatLabel = (label) => label.trim();
+        "#,
+        diagnostics_options(false),
+    );
+
+    assert!(warnings.is_empty());
+}
@@ -0,0 +1,303 @@
+use super::react_refresh;
+use swc_core::{common::chain, ecma::transforms::testing::test};
+
+test!(
+    swc_ecma_parser::Syntax::Es(swc_ecma_parser::EsConfig {
+        jsx: true,
+        ..Default::default()
+    }),
+    |tester| react_refresh(
+        String::from("test"),
+        Default::default(),
+        Some(tester.comments.clone())
+    ),
+    refresh_reset_module,
+    // Input codes
+    r#"
+    // @refresh reset
+    import { useState } from 'react';
+
+    export function ResetComponentA() {
+        const [number, setNumber] = useState(0);
+        return <div>{'Hello, World'}</div>;
+    }
+
+    export function ResetComponentB() {
+        const [number, setNumber] = useState(0);
+        return <div>{'Hello, World'}</div>;
+    }
+    "#,
+    // Output
+    r#"
+    var __prevRefreshReg = global.$RefreshReg$;
+    var __prevRefreshSig = global.$RefreshSig$;
+    global.$RefreshReg$ = global.$RefreshRuntime$.getRegisterFunction();
+    global.$RefreshSig$ = global.$RefreshRuntime$.getCreateSignatureFunction();
+    var __s = global.$RefreshSig$();
+    // @refresh reset
+    import { useState } from 'react';
+    export function ResetComponentA() {
+        __s();
+        const [number, setNumber] = useState(0);
+        return <div>{'Hello, World'}</div>;
+    }
+    export function ResetComponentB() {
+        __s();
+        const [number, setNumber] = useState(0);
+        return <div>{'Hello, World'}</div>;
+    }
+    __s(ResetComponentA, "test:ResetComponentA", true);
+    global.$RefreshReg$(ResetComponentA, "ResetComponentA");
+    global.$RefreshRuntime$.getContext(ResetComponentA).accept();
+    __s(ResetComponentB, "test:ResetComponentB", true);
+    global.$RefreshReg$(ResetComponentB, "ResetComponentB");
+    global.$RefreshRuntime$.getContext(ResetComponentB).accept();
+    global.$RefreshRuntime$.registerModule?.("test", { exports: ["ResetComponentA", "ResetComponentB"], components: ["ResetComponentA", "ResetComponentB"], isBoundary: true });
+    global.$RefreshReg$ = __prevRefreshReg;
+    global.$RefreshSig$ = __prevRefreshSig;
+    "#
+);
+
+test!(
+    swc_ecma_parser::Syntax::Es(swc_ecma_parser::EsConfig {
+        jsx: true,
+        ..Default::default()
+    }),
+    |tester| react_refresh(
+        String::from("test"),
+        Default::default(),
+        Some(tester.comments.clone())
+    ),
+    refresh_reset_component,
+    // Input codes
+    r#"
+    import { useState } from 'react';
+
+    export function KeepComponent() {
+        const [number, setNumber] = useState(0);
+        return <div>{'Hello, World'}</div>;
+    }
+
+    // @refresh reset
+    export function ResetComponent() {
+        const [number, setNumber] = useState(0);
+        return <div>{'Hello, World'}</div>;
+    }
+    "#,
+    // Output
+    r#"
+    var __prevRefreshReg = global.$RefreshReg$;
+    var __prevRefreshSig = global.$RefreshSig$;
+    global.$RefreshReg$ = global.$RefreshRuntime$.getRegisterFunction();
+    global.$RefreshSig$ = global.$RefreshRuntime$.getCreateSignatureFunction();
+    var __s = global.$RefreshSig$();
+    import { useState } from 'react';
+    export function KeepComponent() {
+        __s();
+        const [number, setNumber] = useState(0);
+        return <div>{'Hello, World'}</div>;
+    }
+    // @refresh reset
+    export function ResetComponent() {
+        __s();
+        const [number, setNumber] = useState(0);
+        return <div>{'Hello, World'}</div>;
+    }
+    __s(KeepComponent, "test:KeepComponent", false);
+    global.$RefreshReg$(KeepComponent, "KeepComponent");
+    global.$RefreshRuntime$.getContext(KeepComponent).accept();
+    __s(ResetComponent, "test:ResetComponent", true);
+    global.$RefreshReg$(ResetComponent, "ResetComponent");
+    global.$RefreshRuntime$.getContext(ResetComponent).accept();
+    global.$RefreshRuntime$.registerModule?.("test", { exports: ["KeepComponent", "ResetComponent"], components: ["KeepComponent", "ResetComponent"], isBoundary: true });
+    global.$RefreshReg$ = __prevRefreshReg;
+    global.$RefreshSig$ = __prevRefreshSig;
+    "#
+);
+
+test!(
+    swc_ecma_parser::Syntax::Es(swc_ecma_parser::EsConfig {
+        jsx: true,
+        ..Default::default()
+    }),
+    |tester| react_refresh(
+        String::from("test"),
+        Default::default(),
+        Some(tester.comments.clone())
+    ),
+    refresh_skip_component,
+    // Input codes
+    r#"
+    export function RefreshComponent() {
+        return <div>{'Hello, World'}</div>;
+    }
+
+    // @refresh skip
+    export function SkipComponent() {
+        return <div>{'Hello, World'}</div>;
+    }
+
+    // @refresh skip
+    const SkipArrowComponent = () => {
+        return <div>{'Hello, World'}</div>;
+    };
+    "#,
+    // Output
+    r#"
+    var __prevRefreshReg = global.$RefreshReg$;
+    var __prevRefreshSig = global.$RefreshSig$;
+    global.$RefreshReg$ = global.$RefreshRuntime$.getRegisterFunction();
+    export function RefreshComponent() {
+        return <div>{'Hello, World'}</div>;
+    }
+    // @refresh skip
+    export function SkipComponent() {
+        return <div>{'Hello, World'}</div>;
+    }
+    // @refresh skip
+    const SkipArrowComponent = () => {
+        return <div>{'Hello, World'}</div>;
+    };
+    global.$RefreshReg$(RefreshComponent, "RefreshComponent");
+    global.$RefreshRuntime$.getContext(RefreshComponent).accept();
+    global.$RefreshRuntime$.registerModule?.("test", { exports: ["RefreshComponent", "SkipComponent"], components: ["RefreshComponent"], isBoundary: false });
+    global.$RefreshReg$ = __prevRefreshReg;
+    global.$RefreshSig$ = __prevRefreshSig;
+    "#
+);
+
+test!(
+    swc_ecma_parser::Syntax::Es(swc_ecma_parser::EsConfig {
+        jsx: true,
+        ..Default::default()
+    }),
+    |tester| react_refresh(
+        String::from("test"),
+        Default::default(),
+        Some(tester.comments.clone())
+    ),
+    use_no_refresh_directive,
+    // Input codes
+    r#"
+    'use no refresh';
+
+    export function Component() {
+        return <div>{'Hello, World'}</div>;
+    }
+    "#,
+    // Output
+    r#"
+    'use no refresh';
+    export function Component() {
+        return <div>{'Hello, World'}</div>;
+    }
+    "#
+);
+
+test!(
+    swc_ecma_parser::Syntax::Es(swc_ecma_parser::EsConfig {
+        jsx: true,
+        ..Default::default()
+    }),
+    |tester| react_refresh(
+        String::from("test"),
+        Default::default(),
+        Some(tester.comments.clone())
+    ),
+    refresh_disable_banner,
+    // Input codes
+    r#"
+    /* @refresh-disable */
+    export function Component() {
+        return <div>{'Hello, World'}</div>;
+    }
+    "#,
+    // Output
+    r#"
+    /* @refresh-disable */
+    export function Component() {
+        return <div>{'Hello, World'}</div>;
+    }
+    "#
+);
+
+test!(
+    swc_ecma_parser::Syntax::Es(swc_ecma_parser::EsConfig {
+        jsx: true,
+        ..Default::default()
+    }),
+    |tester| react_refresh(
+        String::from("test"),
+        Default::default(),
+        Some(tester.comments.clone())
+    ),
+    refresh_persist_variable,
+    // Input codes
+    r#"
+    // @refresh persist
+    const cache = new Map();
+
+    // @refresh persist
+    export let mockStore = createMockStore();
+
+    // @refresh persist
+    var ignored = [];
+
+    const notPersisted = {};
+
+    export function PersistComponent() {
+        return <div>{'Hello, World'}</div>;
+    }
+    "#,
+    // Output
+    r#"
+    var __prevRefreshReg = global.$RefreshReg$;
+    var __prevRefreshSig = global.$RefreshSig$;
+    global.$RefreshReg$ = global.$RefreshRuntime$.getRegisterFunction();
+    // @refresh persist
+    const cache = global.$RefreshRuntime$.getPersistedValue("test:cache", () => new Map());
+    // @refresh persist
+    export let mockStore = global.$RefreshRuntime$.getPersistedValue("test:mockStore", () => createMockStore());
+    // @refresh persist
+    var ignored = [];
+    const notPersisted = {};
+    export function PersistComponent() {
+        return <div>{'Hello, World'}</div>;
+    }
+    global.$RefreshReg$(PersistComponent, "PersistComponent");
+    global.$RefreshRuntime$.getContext(PersistComponent).accept();
+    global.$RefreshRuntime$.registerModule?.("test", { exports: ["mockStore", "PersistComponent"], components: ["PersistComponent"], isBoundary: false });
+    global.$RefreshReg$ = __prevRefreshReg;
+    global.$RefreshSig$ = __prevRefreshSig;
+    "#
+);
+
+test!(
+    swc_ecma_parser::Syntax::Es(swc_ecma_parser::EsConfig {
+        jsx: true,
+        ..Default::default()
+    }),
+    |tester| chain!(
+        react_refresh(
+            String::from("test"),
+            Default::default(),
+            Some(tester.comments.clone())
+        ),
+        react_refresh(
+            String::from("test"),
+            Default::default(),
+            Some(tester.comments.clone())
+        )
+    ),
+    refresh_persist_variable_twice,
+    // Input codes
+    r#"
+    // @refresh persist
+    const cache = new Map();
+    "#,
+    // Output
+    r#"
+    // @refresh persist
+    const cache = global.$RefreshRuntime$.getPersistedValue("test:cache", () => new Map());
+    "#
+);
@@ -0,0 +1,273 @@
+use super::react_refresh;
+use swc_core::{common::chain, ecma::transforms::testing::test};
+
+test!(
+    swc_ecma_parser::Syntax::Es(swc_ecma_parser::EsConfig {
+        jsx: true,
+        ..Default::default()
+    }),
+    |tester| react_refresh(
+        String::from("test"),
+        Default::default(),
+        Some(tester.comments.clone())
+    ),
+    refresh_dispose_function,
+    // Input codes
+    r#"
+    const socket = connect();
+
+    export function DisposeComponent() {
+        return <div>{'Hello, World'}</div>;
+    }
+
+    export function __refreshDispose() {
+        socket.close();
+    }
+    "#,
+    // Output
+    r#"
+    var __prevRefreshReg = global.$RefreshReg$;
+    var __prevRefreshSig = global.$RefreshSig$;
+    global.$RefreshReg$ = global.$RefreshRuntime$.getRegisterFunction();
+    const socket = connect();
+    export function DisposeComponent() {
+        return <div>{'Hello, World'}</div>;
+    }
+    export function __refreshDispose() {
+        socket.close();
+    }
+    global.$RefreshRuntime$.addDisposeHandler("test", __refreshDispose);
+    global.$RefreshReg$(DisposeComponent, "DisposeComponent");
+    global.$RefreshRuntime$.getContext(DisposeComponent).accept();
+    global.$RefreshRuntime$.registerModule?.("test", { exports: ["DisposeComponent", "__refreshDispose"], components: ["DisposeComponent"], isBoundary: true });
+    global.$RefreshReg$ = __prevRefreshReg;
+    global.$RefreshSig$ = __prevRefreshSig;
+    "#
+);
+
+test!(
+    swc_ecma_parser::Syntax::Es(swc_ecma_parser::EsConfig {
+        jsx: true,
+        ..Default::default()
+    }),
+    |tester| react_refresh(
+        String::from("test"),
+        Default::default(),
+        Some(tester.comments.clone())
+    ),
+    refresh_dispose_variable,
+    // Input codes
+    r#"
+    const __refreshDispose = () => {
+        clearInterval(timer);
+    };
+
+    function DisposeComponent() {
+        return <div>{'Hello, World'}</div>;
+    }
+    "#,
+    // Output
+    r#"
+    var __prevRefreshReg = global.$RefreshReg$;
+    var __prevRefreshSig = global.$RefreshSig$;
+    global.$RefreshReg$ = global.$RefreshRuntime$.getRegisterFunction();
+    const __refreshDispose = () => {
+        clearInterval(timer);
+    };
+    global.$RefreshRuntime$.addDisposeHandler("test", __refreshDispose);
+    function DisposeComponent() {
+        return <div>{'Hello, World'}</div>;
+    }
+    global.$RefreshReg$(DisposeComponent, "DisposeComponent");
+    global.$RefreshRuntime$.getContext(DisposeComponent).accept();
+    global.$RefreshRuntime$.registerModule?.("test", { exports: [], components: ["DisposeComponent"], isBoundary: false });
+    global.$RefreshReg$ = __prevRefreshReg;
+    global.$RefreshSig$ = __prevRefreshSig;
+    "#
+);
+
+test!(
+    swc_ecma_parser::Syntax::Es(swc_ecma_parser::EsConfig {
+        jsx: true,
+        ..Default::default()
+    }),
+    |tester| react_refresh(
+        String::from("test"),
+        Default::default(),
+        Some(tester.comments.clone())
+    ),
+    import_meta_hot_dispose,
+    // Input codes
+    r#"
+    import.meta.hot.dispose(() => {
+        socket.close();
+    });
+
+    export function DisposeComponent() {
+        return <div>{'Hello, World'}</div>;
+    }
+    "#,
+    // Output
+    r#"
+    var __prevRefreshReg = global.$RefreshReg$;
+    var __prevRefreshSig = global.$RefreshSig$;
+    global.$RefreshReg$ = global.$RefreshRuntime$.getRegisterFunction();
+    import.meta.hot.dispose(global.$RefreshRuntime$.addDisposeHandler("test", () => {
+        socket.close();
+    }));
+    export function DisposeComponent() {
+        return <div>{'Hello, World'}</div>;
+    }
+    global.$RefreshReg$(DisposeComponent, "DisposeComponent");
+    global.$RefreshRuntime$.getContext(DisposeComponent).accept();
+    global.$RefreshRuntime$.registerModule?.("test", { exports: ["DisposeComponent"], components: ["DisposeComponent"], isBoundary: true });
+    global.$RefreshReg$ = __prevRefreshReg;
+    global.$RefreshSig$ = __prevRefreshSig;
+    "#
+);
+
+test!(
+    swc_ecma_parser::Syntax::Es(swc_ecma_parser::EsConfig {
+        jsx: true,
+        ..Default::default()
+    }),
+    |tester| chain!(
+        react_refresh(
+            String::from("test"),
+            Default::default(),
+            Some(tester.comments.clone())
+        ),
+        react_refresh(
+            String::from("test"),
+            Default::default(),
+            Some(tester.comments.clone())
+        )
+    ),
+    refresh_dispose_without_components_twice,
+    // Input codes
+    r#"
+    const socket = connect();
+
+    export function __refreshDispose() {
+        socket.close();
+    }
+    "#,
+    // Output
+    r#"
+    const socket = connect();
+    export function __refreshDispose() {
+        socket.close();
+    }
+    global.$RefreshRuntime$.addDisposeHandler("test", __refreshDispose);
+    "#
+);
+
+test!(
+    swc_ecma_parser::Syntax::Es(swc_ecma_parser::EsConfig {
+        jsx: true,
+        ..Default::default()
+    }),
+    |tester| react_refresh(
+        String::from("test"),
+        Default::default(),
+        Some(tester.comments.clone())
+    ),
+    import_meta_hot_dispose_optional_chain,
+    // Input codes
+    r#"
+    import.meta.hot?.dispose(() => {
+        socket.close();
+    });
+    "#,
+    // Output
+    r#"
+    import.meta.hot?.dispose(global.$RefreshRuntime$.addDisposeHandler("test", () => {
+        socket.close();
+    }));
+    "#
+);
+
+test!(
+    swc_ecma_parser::Syntax::Es(swc_ecma_parser::EsConfig {
+        jsx: true,
+        ..Default::default()
+    }),
+    |tester| react_refresh(
+        String::from("test"),
+        Default::default(),
+        Some(tester.comments.clone())
+    ),
+    import_meta_hot_dispose_guarded,
+    // Input codes
+    r#"
+    if (import.meta.hot) {
+        import.meta.hot.dispose(() => {
+            socket.close();
+        });
+    }
+
+    if (import.meta.hot) import.meta.hot.dispose(() => timer.stop());
+    "#,
+    // Output
+    r#"
+    if (import.meta.hot) {
+        import.meta.hot.dispose(global.$RefreshRuntime$.addDisposeHandler("test", () => {
+            socket.close();
+        }));
+    }
+    if (import.meta.hot) import.meta.hot.dispose(global.$RefreshRuntime$.addDisposeHandler("test", () => timer.stop()));
+    "#
+);
+
+test!(
+    swc_ecma_parser::Syntax::Es(swc_ecma_parser::EsConfig {
+        jsx: true,
+        ..Default::default()
+    }),
+    |tester| chain!(
+        react_refresh(
+            String::from("test"),
+            Default::default(),
+            Some(tester.comments.clone())
+        ),
+        react_refresh(
+            String::from("test"),
+            Default::default(),
+            Some(tester.comments.clone())
+        )
+    ),
+    import_meta_hot_dispose_twice,
+    // Input codes
+    r#"
+    import.meta.hot.dispose(() => {
+        socket.close();
+    });
+    "#,
+    // Output
+    r#"
+    import.meta.hot.dispose(global.$RefreshRuntime$.addDisposeHandler("test", () => {
+        socket.close();
+    }));
+    "#
+);
+
+test!(
+    swc_ecma_parser::Syntax::Es(swc_ecma_parser::EsConfig {
+        jsx: true,
+        ..Default::default()
+    }),
+    |tester| react_refresh(
+        String::from("test"),
+        Default::default(),
+        Some(tester.comments.clone())
+    ),
+    import_meta_hot_dispose_evaluated_once,
+    // Input codes
+    r#"
+    import.meta.hot.dispose(makeHandler());
+    "#,
+    // Output
+    r#"
+    import.meta.hot.dispose(global.$RefreshRuntime$.addDisposeHandler("test", makeHandler()));
+    "#
+);
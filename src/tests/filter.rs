@@ -0,0 +1,29 @@
+use super::file_filter;
+
+#[test]
+fn default_filter() {
+    let filter = file_filter(&None, &None).unwrap();
+
+    assert!(filter.is_match("/app/src/App.tsx"));
+    assert!(filter.is_match("src/App.tsx"));
+    assert!(!filter.is_match("/app/node_modules/react-native/index.js"));
+    assert!(!filter.is_match("node_modules/react-native/index.js"));
+}
+
+#[test]
+fn include_filter() {
+    let filter = file_filter(&Some(vec![String::from("**/src/**")]), &None).unwrap();
+
+    assert!(filter.is_match("/app/src/App.tsx"));
+    assert!(!filter.is_match("/app/vendor/Button.js"));
+    assert!(!filter.is_match("/app/src/node_modules/lib/index.js"));
+}
+
+#[test]
+fn exclude_filter() {
+    let filter = file_filter(&None, &Some(vec![String::from("**/vendor/**")])).unwrap();
+
+    assert!(filter.is_match("/app/src/App.tsx"));
+    assert!(filter.is_match("/app/node_modules/lib/index.js"));
+    assert!(!filter.is_match("/app/vendor/Button.js"));
+}
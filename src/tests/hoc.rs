@@ -6,7 +6,11 @@ test!(
         jsx: true,
         ..Default::default()
     }),
-    |_| react_refresh(String::from("test")),
+    |tester| react_refresh(
+        String::from("test"),
+        Default::default(),
+        Some(tester.comments.clone())
+    ),
     builtin_hoc_component_anonymous,
     // Input codes
     r#"
@@ -24,6 +28,7 @@ test!(
     });
     global.$RefreshReg$(MemoComponentA, "MemoComponentA");
     global.$RefreshRuntime$.getContext(MemoComponentA).accept();
+    global.$RefreshRuntime$.registerModule?.("test", { exports: [], components: ["MemoComponentA"], isBoundary: false });
     global.$RefreshReg$ = __prevRefreshReg;
     global.$RefreshSig$ = __prevRefreshSig;
     "#
@@ -34,7 +39,11 @@ test!(
         jsx: true,
         ..Default::default()
     }),
-    |_| react_refresh(String::from("test")),
+    |tester| react_refresh(
+        String::from("test"),
+        Default::default(),
+        Some(tester.comments.clone())
+    ),
     builtin_hoc_ident_component,
     // Input codes
     r#"
@@ -52,6 +61,7 @@ test!(
     });
     global.$RefreshReg$(MemoComponentB, "MemoComponentB");
     global.$RefreshRuntime$.getContext(MemoComponentB).accept();
+    global.$RefreshRuntime$.registerModule?.("test", { exports: [], components: ["MemoComponentB"], isBoundary: false });
     global.$RefreshReg$ = __prevRefreshReg;
     global.$RefreshSig$ = __prevRefreshSig;
     "#
@@ -62,7 +72,11 @@ test!(
         jsx: true,
         ..Default::default()
     }),
-    |_| react_refresh(String::from("test")),
+    |tester| react_refresh(
+        String::from("test"),
+        Default::default(),
+        Some(tester.comments.clone())
+    ),
     builtin_hoc_fn_only,
     // Input codes
     r#"
@@ -80,6 +94,7 @@ test!(
     });
     global.$RefreshReg$(ForwardedComponent, "ForwardedComponent");
     global.$RefreshRuntime$.getContext(ForwardedComponent).accept();
+    global.$RefreshRuntime$.registerModule?.("test", { exports: [], components: ["ForwardedComponent"], isBoundary: false });
     global.$RefreshReg$ = __prevRefreshReg;
     global.$RefreshSig$ = __prevRefreshSig;
     "#
@@ -90,7 +105,11 @@ test!(
         jsx: true,
         ..Default::default()
     }),
-    |_| react_refresh(String::from("test")),
+    |tester| react_refresh(
+        String::from("test"),
+        Default::default(),
+        Some(tester.comments.clone())
+    ),
     builtin_hoc_component_anonymous_with_named_export,
     // Input codes
     r#"
@@ -108,6 +127,7 @@ test!(
     });
     global.$RefreshReg$(MemoComponentA, "MemoComponentA");
     global.$RefreshRuntime$.getContext(MemoComponentA).accept();
+    global.$RefreshRuntime$.registerModule?.("test", { exports: ["MemoComponentA"], components: ["MemoComponentA"], isBoundary: true });
     global.$RefreshReg$ = __prevRefreshReg;
     global.$RefreshSig$ = __prevRefreshSig;
     "#
@@ -118,7 +138,11 @@ test!(
         jsx: true,
         ..Default::default()
     }),
-    |_| react_refresh(String::from("test")),
+    |tester| react_refresh(
+        String::from("test"),
+        Default::default(),
+        Some(tester.comments.clone())
+    ),
     builtin_hoc_ident_component_with_named_export,
     // Input codes
     r#"
@@ -136,6 +160,7 @@ test!(
     });
     global.$RefreshReg$(MemoComponentB, "MemoComponentB");
     global.$RefreshRuntime$.getContext(MemoComponentB).accept();
+    global.$RefreshRuntime$.registerModule?.("test", { exports: ["MemoComponentB"], components: ["MemoComponentB"], isBoundary: true });
     global.$RefreshReg$ = __prevRefreshReg;
     global.$RefreshSig$ = __prevRefreshSig;
     "#
@@ -146,7 +171,11 @@ test!(
         jsx: true,
         ..Default::default()
     }),
-    |_| react_refresh(String::from("test")),
+    |tester| react_refresh(
+        String::from("test"),
+        Default::default(),
+        Some(tester.comments.clone())
+    ),
     builtin_hoc_fn_only_with_named_export,
     // Input codes
     r#"
@@ -164,6 +193,134 @@ test!(
     });
     global.$RefreshReg$(ForwardedComponent, "ForwardedComponent");
     global.$RefreshRuntime$.getContext(ForwardedComponent).accept();
+    global.$RefreshRuntime$.registerModule?.("test", { exports: ["ForwardedComponent"], components: ["ForwardedComponent"], isBoundary: true });
+    global.$RefreshReg$ = __prevRefreshReg;
+    global.$RefreshSig$ = __prevRefreshSig;
+    "#
+);
+
+test!(
+    swc_ecma_parser::Syntax::Es(swc_ecma_parser::EsConfig {
+        jsx: true,
+        ..Default::default()
+    }),
+    |tester| react_refresh(
+        String::from("test"),
+        Default::default(),
+        Some(tester.comments.clone())
+    ),
+    lazy_component,
+    // Input codes
+    r#"
+    const LazyPage = lazy(() => import('./Page'));
+    export const LazySettings = React.lazy(async () => {
+        const module = await import('./Settings');
+        return { default: module.Settings };
+    });
+    "#,
+    // Output
+    r#"
+    var __prevRefreshReg = global.$RefreshReg$;
+    var __prevRefreshSig = global.$RefreshSig$;
+    global.$RefreshReg$ = global.$RefreshRuntime$.getRegisterFunction();
+    const LazyPage = lazy(() => import('./Page'));
+    export const LazySettings = React.lazy(async () => {
+        const module = await import('./Settings');
+        return { default: module.Settings };
+    });
+    global.$RefreshReg$(LazyPage, "LazyPage");
+    global.$RefreshRuntime$.getContext(LazyPage).accept();
+    global.$RefreshReg$(LazySettings, "LazySettings");
+    global.$RefreshRuntime$.getContext(LazySettings).accept();
+    global.$RefreshRuntime$.registerModule?.("test", { exports: ["LazySettings"], components: ["LazyPage", "LazySettings"], isBoundary: true });
+    global.$RefreshReg$ = __prevRefreshReg;
+    global.$RefreshSig$ = __prevRefreshSig;
+    "#
+);
+
+test!(
+    swc_ecma_parser::Syntax::Es(swc_ecma_parser::EsConfig {
+        jsx: true,
+        ..Default::default()
+    }),
+    |tester| react_refresh(
+        String::from("test"),
+        Default::default(),
+        Some(tester.comments.clone())
+    ),
+    lazy_only_module,
+    // Input codes
+    r#"
+    const Page = lazy(() => import("./Page"));
+    export default Page;
+    "#,
+    // Output
+    r#"
+    var __prevRefreshReg = global.$RefreshReg$;
+    var __prevRefreshSig = global.$RefreshSig$;
+    global.$RefreshReg$ = global.$RefreshRuntime$.getRegisterFunction();
+    const Page = lazy(() => import("./Page"));
+    export default Page;
+    global.$RefreshReg$(Page, "Page");
+    global.$RefreshRuntime$.getContext(Page).accept();
+    global.$RefreshRuntime$.registerModule?.("test", { exports: ["default"], components: ["Page"], isBoundary: true });
+    global.$RefreshReg$ = __prevRefreshReg;
+    global.$RefreshSig$ = __prevRefreshSig;
+    "#
+);
+
+test!(
+    swc_ecma_parser::Syntax::Es(swc_ecma_parser::EsConfig {
+        jsx: true,
+        ..Default::default()
+    }),
+    |tester| react_refresh(
+        String::from("test"),
+        Default::default(),
+        Some(tester.comments.clone())
+    ),
+    reassigned_hoc_component,
+    // Input codes
+    r#"
+    let ReassignedComponentA = () => {
+        const [number, setNumber] = useState(0);
+        return <div>{'Hello, World'}</div>;
+    };
+    ReassignedComponentA = memo(ReassignedComponentA);
+
+    function ReassignedComponentB() {
+        return <div>{'Hello, World'}</div>;
+    }
+    ReassignedComponentB = React.memo(ReassignedComponentB);
+    ReassignedComponentB = withTheme(ReassignedComponentB);
+    "#,
+    // Output
+    r#"
+    var __prevRefreshReg = global.$RefreshReg$;
+    var __prevRefreshSig = global.$RefreshSig$;
+    global.$RefreshReg$ = global.$RefreshRuntime$.getRegisterFunction();
+    global.$RefreshSig$ = global.$RefreshRuntime$.getCreateSignatureFunction();
+    var __s = global.$RefreshSig$();
+    let ReassignedComponentA = () => {
+        __s();
+        const [number, setNumber] = useState(0);
+        return <div>{'Hello, World'}</div>;
+    };
+    __s(ReassignedComponentA, "test:ReassignedComponentA", false);
+    global.$RefreshReg$(ReassignedComponentA, "ReassignedComponentA$memo");
+    ReassignedComponentA = memo(ReassignedComponentA);
+    function ReassignedComponentB() {
+        return <div>{'Hello, World'}</div>;
+    }
+    global.$RefreshReg$(ReassignedComponentB, "ReassignedComponentB$memo");
+    ReassignedComponentB = React.memo(ReassignedComponentB);
+    global.$RefreshReg$(ReassignedComponentB, "ReassignedComponentB$withTheme");
+    ReassignedComponentB = withTheme(ReassignedComponentB);
+    global.$RefreshReg$(ReassignedComponentA, "ReassignedComponentA");
+    global.$RefreshRuntime$.getContext(ReassignedComponentA).accept();
+    global.$RefreshReg$(ReassignedComponentB, "ReassignedComponentB");
+    global.$RefreshRuntime$.getContext(ReassignedComponentB).accept();
+    global.$RefreshRuntime$.registerModule?.("test", { exports: [], components: ["ReassignedComponentA", "ReassignedComponentB"], isBoundary: false });
     global.$RefreshReg$ = __prevRefreshReg;
     global.$RefreshSig$ = __prevRefreshSig;
     "#
@@ -1,4 +1,5 @@
 use super::react_refresh;
+use crate::options::TransformOptions;
 use swc_core::ecma::transforms::testing::test;
 
 test!(
@@ -6,7 +7,11 @@ test!(
         jsx: true,
         ..Default::default()
     }),
-    |_| react_refresh(String::from("test")),
+    |tester| react_refresh(
+        String::from("test"),
+        Default::default(),
+        Some(tester.comments.clone())
+    ),
     no_hook_component,
     // Input codes
     r#"
@@ -24,6 +29,7 @@ test!(
     }
     global.$RefreshReg$(NoHookComponent, "NoHookComponent");
     global.$RefreshRuntime$.getContext(NoHookComponent).accept();
+    global.$RefreshRuntime$.registerModule?.("test", { exports: ["NoHookComponent"], components: ["NoHookComponent"], isBoundary: true });
     global.$RefreshReg$ = __prevRefreshReg;
     global.$RefreshSig$ = __prevRefreshSig;
     "#
@@ -34,7 +40,11 @@ test!(
         jsx: true,
         ..Default::default()
     }),
-    |_| react_refresh(String::from("test")),
+    |tester| react_refresh(
+        String::from("test"),
+        Default::default(),
+        Some(tester.comments.clone())
+    ),
     non_declare_internal_hook_component,
     // Input codes
     r#"
@@ -59,6 +69,7 @@ test!(
     __s(NonDeclBuiltinHook, "test:NonDeclBuiltinHook", false);
     global.$RefreshReg$(NonDeclBuiltinHook, "NonDeclBuiltinHook");
     global.$RefreshRuntime$.getContext(NonDeclBuiltinHook).accept();
+    global.$RefreshRuntime$.registerModule?.("test", { exports: ["NonDeclBuiltinHook"], components: ["NonDeclBuiltinHook"], isBoundary: true });
     global.$RefreshReg$ = __prevRefreshReg;
     global.$RefreshSig$ = __prevRefreshSig;
     "#
@@ -69,7 +80,11 @@ test!(
         jsx: true,
         ..Default::default()
     }),
-    |_| react_refresh(String::from("test")),
+    |tester| react_refresh(
+        String::from("test"),
+        Default::default(),
+        Some(tester.comments.clone())
+    ),
     declare_internal_hook_component,
     // Input codes
     r#"
@@ -94,6 +109,7 @@ test!(
     __s(DeclBuiltinHook, "test:DeclBuiltinHook", false);
     global.$RefreshReg$(DeclBuiltinHook, "DeclBuiltinHook");
     global.$RefreshRuntime$.getContext(DeclBuiltinHook).accept();
+    global.$RefreshRuntime$.registerModule?.("test", { exports: ["DeclBuiltinHook"], components: ["DeclBuiltinHook"], isBoundary: true });
     global.$RefreshReg$ = __prevRefreshReg;
     global.$RefreshSig$ = __prevRefreshSig;
     "#
@@ -104,7 +120,11 @@ test!(
         jsx: true,
         ..Default::default()
     }),
-    |_| react_refresh(String::from("test")),
+    |tester| react_refresh(
+        String::from("test"),
+        Default::default(),
+        Some(tester.comments.clone())
+    ),
     mixed_builtin_hooks_component,
     // Input codes
     r#"
@@ -139,6 +159,7 @@ test!(
     __s(MixedBuiltinHooks, "test:MixedBuiltinHooks", false);
     global.$RefreshReg$(MixedBuiltinHooks, "MixedBuiltinHooks");
     global.$RefreshRuntime$.getContext(MixedBuiltinHooks).accept();
+    global.$RefreshRuntime$.registerModule?.("test", { exports: ["MixedBuiltinHooks"], components: ["MixedBuiltinHooks"], isBoundary: true });
     global.$RefreshReg$ = __prevRefreshReg;
     global.$RefreshSig$ = __prevRefreshSig;
     "#
@@ -149,7 +170,11 @@ test!(
         jsx: true,
         ..Default::default()
     }),
-    |_| react_refresh(String::from("test")),
+    |tester| react_refresh(
+        String::from("test"),
+        Default::default(),
+        Some(tester.comments.clone())
+    ),
     non_declare_custom_hook_component,
     // Input codes
     r#"
@@ -174,6 +199,7 @@ test!(
     __s(NonDeclCustomHook, "test:NonDeclCustomHook", true);
     global.$RefreshReg$(NonDeclCustomHook, "NonDeclCustomHook");
     global.$RefreshRuntime$.getContext(NonDeclCustomHook).accept();
+    global.$RefreshRuntime$.registerModule?.("test", { exports: ["NonDeclCustomHook"], components: ["NonDeclCustomHook"], isBoundary: true });
     global.$RefreshReg$ = __prevRefreshReg;
     global.$RefreshSig$ = __prevRefreshSig;
     "#
@@ -184,7 +210,11 @@ test!(
         jsx: true,
         ..Default::default()
     }),
-    |_| react_refresh(String::from("test")),
+    |tester| react_refresh(
+        String::from("test"),
+        Default::default(),
+        Some(tester.comments.clone())
+    ),
     declare_custom_hook_component,
     // Input codes
     r#"
@@ -209,6 +239,7 @@ test!(
     __s(DeclCustomHook, "test:DeclCustomHook", true);
     global.$RefreshReg$(DeclCustomHook, "DeclCustomHook");
     global.$RefreshRuntime$.getContext(DeclCustomHook).accept();
+    global.$RefreshRuntime$.registerModule?.("test", { exports: ["DeclCustomHook"], components: ["DeclCustomHook"], isBoundary: true });
     global.$RefreshReg$ = __prevRefreshReg;
     global.$RefreshSig$ = __prevRefreshSig;
     "#
@@ -219,7 +250,11 @@ test!(
         jsx: true,
         ..Default::default()
     }),
-    |_| react_refresh(String::from("test")),
+    |tester| react_refresh(
+        String::from("test"),
+        Default::default(),
+        Some(tester.comments.clone())
+    ),
     mixed_custom_hooks_component,
     // Input codes
     r#"
@@ -247,6 +282,7 @@ test!(
     __s(MixedCustomHooks, "test:MixedCustomHooks", true);
     global.$RefreshReg$(MixedCustomHooks, "MixedCustomHooks");
     global.$RefreshRuntime$.getContext(MixedCustomHooks).accept();
+    global.$RefreshRuntime$.registerModule?.("test", { exports: ["MixedCustomHooks"], components: ["MixedCustomHooks"], isBoundary: true });
     global.$RefreshReg$ = __prevRefreshReg;
     global.$RefreshSig$ = __prevRefreshSig;
     "#
@@ -257,7 +293,11 @@ test!(
         jsx: true,
         ..Default::default()
     }),
-    |_| react_refresh(String::from("test")),
+    |tester| react_refresh(
+        String::from("test"),
+        Default::default(),
+        Some(tester.comments.clone())
+    ),
     mixed_hooks_component,
     // Input codes
     r#"
@@ -293,6 +333,7 @@ test!(
     __s(MixedHooks, "test:MixedHooks", true);
     global.$RefreshReg$(MixedHooks, "MixedHooks");
     global.$RefreshRuntime$.getContext(MixedHooks).accept();
+    global.$RefreshRuntime$.registerModule?.("test", { exports: ["MixedHooks"], components: ["MixedHooks"], isBoundary: true });
     global.$RefreshReg$ = __prevRefreshReg;
     global.$RefreshSig$ = __prevRefreshSig;
     "#
@@ -303,7 +344,11 @@ test!(
         jsx: true,
         ..Default::default()
     }),
-    |_| react_refresh(String::from("test")),
+    |tester| react_refresh(
+        String::from("test"),
+        Default::default(),
+        Some(tester.comments.clone())
+    ),
     builtin_hook_from_member,
     // Input codes
     r#"
@@ -327,6 +372,7 @@ test!(
     __s(BuiltinMemberHook, "test:BuiltinMemberHook", false);
     global.$RefreshReg$(BuiltinMemberHook, "BuiltinMemberHook");
     global.$RefreshRuntime$.getContext(BuiltinMemberHook).accept();
+    global.$RefreshRuntime$.registerModule?.("test", { exports: ["BuiltinMemberHook"], components: ["BuiltinMemberHook"], isBoundary: true });
     global.$RefreshReg$ = __prevRefreshReg;
     global.$RefreshSig$ = __prevRefreshSig;
     "#
@@ -337,7 +383,11 @@ test!(
         jsx: true,
         ..Default::default()
     }),
-    |_| react_refresh(String::from("test")),
+    |tester| react_refresh(
+        String::from("test"),
+        Default::default(),
+        Some(tester.comments.clone())
+    ),
     custom_hook_from_member,
     // Input codes
     r#"
@@ -361,6 +411,432 @@ test!(
     __s(CustomMemberHook, "test:CustomMemberHook", true);
     global.$RefreshReg$(CustomMemberHook, "CustomMemberHook");
     global.$RefreshRuntime$.getContext(CustomMemberHook).accept();
+    global.$RefreshRuntime$.registerModule?.("test", { exports: ["CustomMemberHook"], components: ["CustomMemberHook"], isBoundary: true });
+    global.$RefreshReg$ = __prevRefreshReg;
+    global.$RefreshSig$ = __prevRefreshSig;
+    "#
+);
+
+test!(
+    swc_ecma_parser::Syntax::Es(swc_ecma_parser::EsConfig {
+        jsx: true,
+        ..Default::default()
+    }),
+    |tester| react_refresh(
+        String::from("test"),
+        Default::default(),
+        Some(tester.comments.clone())
+    ),
+    modern_builtin_hooks_component,
+    // Input codes
+    r#"
+    export function ModernBuiltinHooks() {
+        const id = useId();
+        const [isPending, startTransition] = useTransition();
+        const deferredValue = useDeferredValue(value);
+        const state = useSyncExternalStore(subscribe, getSnapshot);
+        useInsertionEffect(() => {});
+        const [optimisticState, addOptimistic] = useOptimistic(state);
+        const [actionState, formAction] = useActionState(action, null);
+        const { pending } = useFormStatus();
+        const theme = use(ThemeContext);
+        return <div>{'Hello, World'}</div>;
+    }
+    "#,
+    // Output
+    r#"
+    var __prevRefreshReg = global.$RefreshReg$;
+    var __prevRefreshSig = global.$RefreshSig$;
+    global.$RefreshReg$ = global.$RefreshRuntime$.getRegisterFunction();
+    global.$RefreshSig$ = global.$RefreshRuntime$.getCreateSignatureFunction();
+    var __s = global.$RefreshSig$();
+    export function ModernBuiltinHooks() {
+        __s();
+        const id = useId();
+        const [isPending, startTransition] = useTransition();
+        const deferredValue = useDeferredValue(value);
+        const state = useSyncExternalStore(subscribe, getSnapshot);
+        useInsertionEffect(() => {});
+        const [optimisticState, addOptimistic] = useOptimistic(state);
+        const [actionState, formAction] = useActionState(action, null);
+        const { pending } = useFormStatus();
+        const theme = use(ThemeContext);
+        return <div>{'Hello, World'}</div>;
+    }
+    __s(ModernBuiltinHooks, "test:ModernBuiltinHooks", false);
+    global.$RefreshReg$(ModernBuiltinHooks, "ModernBuiltinHooks");
+    global.$RefreshRuntime$.getContext(ModernBuiltinHooks).accept();
+    global.$RefreshRuntime$.registerModule?.("test", { exports: ["ModernBuiltinHooks"], components: ["ModernBuiltinHooks"], isBoundary: true });
+    global.$RefreshReg$ = __prevRefreshReg;
+    global.$RefreshSig$ = __prevRefreshSig;
+    "#
+);
+
+test!(
+    swc_ecma_parser::Syntax::Es(swc_ecma_parser::EsConfig {
+        jsx: true,
+        ..Default::default()
+    }),
+    |tester| react_refresh(
+        String::from("test"),
+        TransformOptions {
+            additional_builtin_hooks: vec![String::from("useWindowDimensions")],
+            ..Default::default()
+        },
+        Some(tester.comments.clone())
+    ),
+    additional_builtin_hooks_component,
+    // Input codes
+    r#"
+    export function AdditionalBuiltinHooks() {
+        const [number, setNumber] = useState(0);
+        const { width, height } = useWindowDimensions();
+        return <div>{'Hello, World'}</div>;
+    }
+    "#,
+    // Output
+    r#"
+    var __prevRefreshReg = global.$RefreshReg$;
+    var __prevRefreshSig = global.$RefreshSig$;
+    global.$RefreshReg$ = global.$RefreshRuntime$.getRegisterFunction();
+    global.$RefreshSig$ = global.$RefreshRuntime$.getCreateSignatureFunction();
+    var __s = global.$RefreshSig$();
+    export function AdditionalBuiltinHooks() {
+        __s();
+        const [number, setNumber] = useState(0);
+        const { width, height } = useWindowDimensions();
+        return <div>{'Hello, World'}</div>;
+    }
+    __s(AdditionalBuiltinHooks, "test:AdditionalBuiltinHooks", false);
+    global.$RefreshReg$(AdditionalBuiltinHooks, "AdditionalBuiltinHooks");
+    global.$RefreshRuntime$.getContext(AdditionalBuiltinHooks).accept();
+    global.$RefreshRuntime$.registerModule?.("test", { exports: ["AdditionalBuiltinHooks"], components: ["AdditionalBuiltinHooks"], isBoundary: true });
+    global.$RefreshReg$ = __prevRefreshReg;
+    global.$RefreshSig$ = __prevRefreshSig;
+    "#
+);
+
+test!(
+    swc_ecma_parser::Syntax::Es(swc_ecma_parser::EsConfig {
+        jsx: true,
+        ..Default::default()
+    }),
+    |tester| react_refresh(
+        String::from("test"),
+        TransformOptions {
+            builtin_hooks: Some(vec![String::from("useWindowDimensions")]),
+            ..Default::default()
+        },
+        Some(tester.comments.clone())
+    ),
+    custom_builtin_hooks_component,
+    // Input codes
+    r#"
+    export function CustomBuiltinHooks() {
+        const [number, setNumber] = useState(0);
+        const { width, height } = useWindowDimensions();
+        return <div>{'Hello, World'}</div>;
+    }
+    "#,
+    // Output
+    r#"
+    var __prevRefreshReg = global.$RefreshReg$;
+    var __prevRefreshSig = global.$RefreshSig$;
+    global.$RefreshReg$ = global.$RefreshRuntime$.getRegisterFunction();
+    global.$RefreshSig$ = global.$RefreshRuntime$.getCreateSignatureFunction();
+    var __s = global.$RefreshSig$();
+    export function CustomBuiltinHooks() {
+        __s();
+        const [number, setNumber] = useState(0);
+        const { width, height } = useWindowDimensions();
+        return <div>{'Hello, World'}</div>;
+    }
+    __s(CustomBuiltinHooks, "test:CustomBuiltinHooks", true);
+    global.$RefreshReg$(CustomBuiltinHooks, "CustomBuiltinHooks");
+    global.$RefreshRuntime$.getContext(CustomBuiltinHooks).accept();
+    global.$RefreshRuntime$.registerModule?.("test", { exports: ["CustomBuiltinHooks"], components: ["CustomBuiltinHooks"], isBoundary: true });
+    global.$RefreshReg$ = __prevRefreshReg;
+    global.$RefreshSig$ = __prevRefreshSig;
+    "#
+);
+
+test!(
+    swc_ecma_parser::Syntax::Es(swc_ecma_parser::EsConfig {
+        jsx: true,
+        ..Default::default()
+    }),
+    |tester| react_refresh(
+        String::from("test"),
+        Default::default(),
+        Some(tester.comments.clone())
+    ),
+    non_hook_functions_component,
+    // Input codes
+    r#"
+    export function NonHookFunctions() {
+        const current = user();
+        useful();
+        const data = App.username();
+
+        return <div>{'Hello, World'}</div>;
+    }
+    "#,
+    // Output
+    r#"
+    var __prevRefreshReg = global.$RefreshReg$;
+    var __prevRefreshSig = global.$RefreshSig$;
+    global.$RefreshReg$ = global.$RefreshRuntime$.getRegisterFunction();
+    export function NonHookFunctions() {
+        const current = user();
+        useful();
+        const data = App.username();
+
+        return <div>{'Hello, World'}</div>;
+    }
+    global.$RefreshReg$(NonHookFunctions, "NonHookFunctions");
+    global.$RefreshRuntime$.getContext(NonHookFunctions).accept();
+    global.$RefreshRuntime$.registerModule?.("test", { exports: ["NonHookFunctions"], components: ["NonHookFunctions"], isBoundary: true });
+    global.$RefreshReg$ = __prevRefreshReg;
+    global.$RefreshSig$ = __prevRefreshSig;
+    "#
+);
+
+test!(
+    swc_ecma_parser::Syntax::Es(swc_ecma_parser::EsConfig {
+        jsx: true,
+        ..Default::default()
+    }),
+    |tester| react_refresh(
+        String::from("test"),
+        Default::default(),
+        Some(tester.comments.clone())
+    ),
+    custom_hook_from_namespace_member,
+    // Input codes
+    r#"
+    export function NamespaceHook() {
+        const thing = App.useThing();
+        const user = useUser.data();
+
+        return <div>{'Hello, World'}</div>;
+    }
+    "#,
+    // Output
+    r#"
+    var __prevRefreshReg = global.$RefreshReg$;
+    var __prevRefreshSig = global.$RefreshSig$;
+    global.$RefreshReg$ = global.$RefreshRuntime$.getRegisterFunction();
+    global.$RefreshSig$ = global.$RefreshRuntime$.getCreateSignatureFunction();
+    var __s = global.$RefreshSig$();
+    export function NamespaceHook() {
+        __s();
+        const thing = App.useThing();
+        const user = useUser.data();
+
+        return <div>{'Hello, World'}</div>;
+    }
+    __s(NamespaceHook, "test:NamespaceHook", true);
+    global.$RefreshReg$(NamespaceHook, "NamespaceHook");
+    global.$RefreshRuntime$.getContext(NamespaceHook).accept();
+    global.$RefreshRuntime$.registerModule?.("test", { exports: ["NamespaceHook"], components: ["NamespaceHook"], isBoundary: true });
+    global.$RefreshReg$ = __prevRefreshReg;
+    global.$RefreshSig$ = __prevRefreshSig;
+    "#
+);
+
+test!(
+    swc_ecma_parser::Syntax::Es(swc_ecma_parser::EsConfig {
+        jsx: true,
+        ..Default::default()
+    }),
+    |tester| react_refresh(
+        String::from("test"),
+        TransformOptions {
+            hook_pattern: Some(String::from("^(use|with)[A-Z]")),
+            ..Default::default()
+        },
+        Some(tester.comments.clone())
+    ),
+    hook_pattern_component,
+    // Input codes
+    r#"
+    export function HookPattern() {
+        const theme = withTheme();
+        const current = useful();
+
+        return <div>{'Hello, World'}</div>;
+    }
+    "#,
+    // Output
+    r#"
+    var __prevRefreshReg = global.$RefreshReg$;
+    var __prevRefreshSig = global.$RefreshSig$;
+    global.$RefreshReg$ = global.$RefreshRuntime$.getRegisterFunction();
+    global.$RefreshSig$ = global.$RefreshRuntime$.getCreateSignatureFunction();
+    var __s = global.$RefreshSig$();
+    export function HookPattern() {
+        __s();
+        const theme = withTheme();
+        const current = useful();
+
+        return <div>{'Hello, World'}</div>;
+    }
+    __s(HookPattern, "test:HookPattern", true);
+    global.$RefreshReg$(HookPattern, "HookPattern");
+    global.$RefreshRuntime$.getContext(HookPattern).accept();
+    global.$RefreshRuntime$.registerModule?.("test", { exports: ["HookPattern"], components: ["HookPattern"], isBoundary: true });
+    global.$RefreshReg$ = __prevRefreshReg;
+    global.$RefreshSig$ = __prevRefreshSig;
+    "#
+);
+
+test!(
+    swc_ecma_parser::Syntax::Es(swc_ecma_parser::EsConfig {
+        jsx: true,
+        ..Default::default()
+    }),
+    |tester| react_refresh(
+        String::from("test"),
+        Default::default(),
+        Some(tester.comments.clone())
+    ),
+    imported_hook_aliases_component,
+    // Input codes
+    r#"
+    import * as R from 'react';
+    import { useState as useS } from 'react';
+    import { useEffect as useE } from 'preact/hooks';
+
+    export function ImportedHookAliases() {
+        const [number, setNumber] = useS(0);
+        const ref = R.useRef(null);
+        useE(() => {}, []);
+
+        return <div>{'Hello, World'}</div>;
+    }
+    "#,
+    // Output
+    r#"
+    var __prevRefreshReg = global.$RefreshReg$;
+    var __prevRefreshSig = global.$RefreshSig$;
+    global.$RefreshReg$ = global.$RefreshRuntime$.getRegisterFunction();
+    global.$RefreshSig$ = global.$RefreshRuntime$.getCreateSignatureFunction();
+    var __s = global.$RefreshSig$();
+    import * as R from 'react';
+    import { useState as useS } from 'react';
+    import { useEffect as useE } from 'preact/hooks';
+    export function ImportedHookAliases() {
+        __s();
+        const [number, setNumber] = useS(0);
+        const ref = R.useRef(null);
+        useE(() => {}, []);
+
+        return <div>{'Hello, World'}</div>;
+    }
+    __s(ImportedHookAliases, "test:ImportedHookAliases", false);
+    global.$RefreshReg$(ImportedHookAliases, "ImportedHookAliases");
+    global.$RefreshRuntime$.getContext(ImportedHookAliases).accept();
+    global.$RefreshRuntime$.registerModule?.("test", { exports: ["ImportedHookAliases"], components: ["ImportedHookAliases"], isBoundary: true });
+    global.$RefreshReg$ = __prevRefreshReg;
+    global.$RefreshSig$ = __prevRefreshSig;
+    "#
+);
+
+test!(
+    swc_ecma_parser::Syntax::Es(swc_ecma_parser::EsConfig {
+        jsx: true,
+        ..Default::default()
+    }),
+    |tester| react_refresh(
+        String::from("test"),
+        TransformOptions {
+            additional_hook_sources: vec![String::from("react-native")],
+            ..Default::default()
+        },
+        Some(tester.comments.clone())
+    ),
+    additional_hook_sources_component,
+    // Input codes
+    r#"
+    import { useWindowDimensions } from 'react-native';
+    import { useStore } from './store';
+
+    export function HookSources() {
+        const { width, height } = useWindowDimensions();
+        const store = useStore();
+
+        return <div>{'Hello, World'}</div>;
+    }
+    "#,
+    // Output
+    r#"
+    var __prevRefreshReg = global.$RefreshReg$;
+    var __prevRefreshSig = global.$RefreshSig$;
+    global.$RefreshReg$ = global.$RefreshRuntime$.getRegisterFunction();
+    global.$RefreshSig$ = global.$RefreshRuntime$.getCreateSignatureFunction();
+    var __s = global.$RefreshSig$();
+    import { useWindowDimensions } from 'react-native';
+    import { useStore } from './store';
+    export function HookSources() {
+        __s();
+        const { width, height } = useWindowDimensions();
+        const store = useStore();
+
+        return <div>{'Hello, World'}</div>;
+    }
+    __s(HookSources, "test:HookSources", true);
+    global.$RefreshReg$(HookSources, "HookSources");
+    global.$RefreshRuntime$.getContext(HookSources).accept();
+    global.$RefreshRuntime$.registerModule?.("test", { exports: ["HookSources"], components: ["HookSources"], isBoundary: true });
+    global.$RefreshReg$ = __prevRefreshReg;
+    global.$RefreshSig$ = __prevRefreshSig;
+    "#
+);
+
+test!(
+    swc_ecma_parser::Syntax::Es(swc_ecma_parser::EsConfig {
+        jsx: true,
+        ..Default::default()
+    }),
+    |tester| react_refresh(
+        String::from("test"),
+        TransformOptions {
+            additional_hook_sources: vec![String::from("react-native")],
+            ..Default::default()
+        },
+        Some(tester.comments.clone())
+    ),
+    stable_hook_sources_component,
+    // Input codes
+    r#"
+    import { useWindowDimensions, useColorScheme as useScheme } from 'react-native';
+
+    export function StableHookSources() {
+        const { width, height } = useWindowDimensions();
+        const scheme = useScheme();
+
+        return <div>{'Hello, World'}</div>;
+    }
+    "#,
+    // Output
+    r#"
+    var __prevRefreshReg = global.$RefreshReg$;
+    var __prevRefreshSig = global.$RefreshSig$;
+    global.$RefreshReg$ = global.$RefreshRuntime$.getRegisterFunction();
+    global.$RefreshSig$ = global.$RefreshRuntime$.getCreateSignatureFunction();
+    var __s = global.$RefreshSig$();
+    import { useWindowDimensions, useColorScheme as useScheme } from 'react-native';
+    export function StableHookSources() {
+        __s();
+        const { width, height } = useWindowDimensions();
+        const scheme = useScheme();
+
+        return <div>{'Hello, World'}</div>;
+    }
+    __s(StableHookSources, "test:StableHookSources", false);
+    global.$RefreshReg$(StableHookSources, "StableHookSources");
+    global.$RefreshRuntime$.getContext(StableHookSources).accept();
+    global.$RefreshRuntime$.registerModule?.("test", { exports: ["StableHookSources"], components: ["StableHookSources"], isBoundary: true });
     global.$RefreshReg$ = __prevRefreshReg;
     global.$RefreshSig$ = __prevRefreshSig;
     "#
@@ -0,0 +1,149 @@
+use super::warnings::{get_warnings, get_warnings_with_source};
+use crate::options::TransformOptions;
+
+/// Returns options that enable or disable `lint`.
+fn lint_options(lint: bool) -> TransformOptions {
+    TransformOptions {
+        lint,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn lint_conditional_hook_calls() {
+    let warnings = get_warnings(
+        r#"
+        export function ConditionalHooks({ enabled, items }) {
+            if (enabled) {
+                useEffect(() => {});
+            }
+            const value = enabled ? useMemo(() => 1, []) : null;
+            const ref = enabled && useRef(null);
+            for (const item of items) {
+                useState(item);
+            }
+            return <div>{value}</div>;
+        }
+        "#,
+        lint_options(true),
+    );
+
+    assert_eq!(
+        warnings,
+        vec![
+            "React Hook \"useEffect\" is called conditionally. React Hooks must be called in the exact same order in every component render.",
+            "React Hook \"useMemo\" is called conditionally. React Hooks must be called in the exact same order in every component render.",
+            "React Hook \"useRef\" is called conditionally. React Hooks must be called in the exact same order in every component render.",
+            "React Hook \"useState\" may be executed more than once. Possibly because it is called in a loop.",
+        ]
+    );
+}
+
+#[test]
+fn lint_hook_calls_after_early_return() {
+    let warnings = get_warnings(
+        r#"
+        export function EarlyReturn({ visible }) {
+            const [number, setNumber] = useState(0);
+            if (!visible) {
+                return null;
+            }
+            const callback = useCallback(() => {
+                return number;
+            }, [number]);
+            return <div>{number}</div>;
+        }
+        "#,
+        lint_options(true),
+    );
+
+    assert_eq!(
+        warnings,
+        vec![
+            "React Hook \"useCallback\" is called after an early return. React Hooks must be called in the exact same order in every component render.",
+        ]
+    );
+}
+
+#[test]
+fn lint_valid_hook_calls() {
+    let warnings = get_warnings(
+        r#"
+        export function ValidHooks({ items }) {
+            const [number, setNumber] = useState(0);
+            const callback = useCallback(() => {
+                if (number > 0) {
+                    return items.map((item) => item.useless());
+                }
+                return null;
+            }, [number]);
+            return <div>{number}</div>;
+        }
+        "#,
+        lint_options(true),
+    );
+
+    assert!(warnings.is_empty());
+}
+
+#[test]
+fn lint_disabled() {
+    let warnings = get_warnings(
+        r#"
+        export function ConditionalHooks({ enabled }) {
+            if (enabled) {
+                useEffect(() => {});
+            }
+            return <div />;
+        }
+        "#,
+        lint_options(false),
+    );
+
+    assert!(warnings.is_empty());
+}
+
+#[test]
+fn lint_use_hook_calls() {
+    let warnings = get_warnings(
+        r#"
+        import { use as useResource } from 'react';
+
+        export function Resource({ enabled, context, promises }) {
+            if (enabled) {
+                use(context);
+            }
+            for (const promise of promises) {
+                useResource(promise);
+            }
+            return <div />;
+        }
+        "#,
+        lint_options(true),
+    );
+
+    assert!(warnings.is_empty());
+}
+
+#[test]
+fn lint_warning_span() {
+    let warnings = get_warnings_with_source(
+        r#"
+        export function Conditional({ enabled }) {
+            if (enabled) {
+                useEffect(() => {});
+            }
+            return <div />;
+        }
+        "#,
+        lint_options(true),
+    );
+
+    assert_eq!(
+        warnings,
+        vec![(
+            String::from("React Hook \"useEffect\" is called conditionally. React Hooks must be called in the exact same order in every component render."),
+            String::from("useEffect(() => {})"),
+        )]
+    );
+}
@@ -0,0 +1,275 @@
+use super::react_refresh;
+use crate::options::TransformOptions;
+use swc_core::ecma::transforms::testing::test;
+
+test!(
+    swc_ecma_parser::Syntax::Es(swc_ecma_parser::EsConfig {
+        jsx: true,
+        ..Default::default()
+    }),
+    |tester| react_refresh(
+        String::from("test"),
+        TransformOptions {
+            nested_components: true,
+            ..Default::default()
+        },
+        Some(tester.comments.clone())
+    ),
+    nested_component_in_factory,
+    // Input codes
+    r#"
+    export function createScreen() {
+        function Header() {
+            const [title, setTitle] = useState('');
+            return <div>{title}</div>;
+        }
+
+        const Footer = () => {
+            return <div>{'Footer'}</div>;
+        };
+
+        return function Screen() {
+            useEffect(() => {});
+            return <Header />;
+        };
+    }
+    "#,
+    // Output
+    r#"
+    var __prevRefreshReg = global.$RefreshReg$;
+    var __prevRefreshSig = global.$RefreshSig$;
+    global.$RefreshReg$ = global.$RefreshRuntime$.getRegisterFunction();
+    global.$RefreshSig$ = global.$RefreshRuntime$.getCreateSignatureFunction();
+    var _s = global.$RefreshSig$();
+    var _s2 = global.$RefreshSig$();
+    export function createScreen() {
+        function Header() {
+            _s();
+            const [title, setTitle] = useState('');
+            return <div>{title}</div>;
+        }
+        _s(Header, "test:createScreen/Header", false);
+        const Footer = () => {
+            return <div>{'Footer'}</div>;
+        };
+        return _s2(function Screen() {
+            _s2();
+            useEffect(() => {});
+            return <Header />;
+        }, "test:createScreen/Screen", false);
+    }
+    global.$RefreshRuntime$.registerModule?.("test", { exports: ["createScreen"], components: [], isBoundary: false });
+    global.$RefreshReg$ = __prevRefreshReg;
+    global.$RefreshSig$ = __prevRefreshSig;
+    "#
+);
+
+test!(
+    swc_ecma_parser::Syntax::Es(swc_ecma_parser::EsConfig {
+        jsx: true,
+        ..Default::default()
+    }),
+    |tester| react_refresh(
+        String::from("test"),
+        TransformOptions {
+            nested_components: true,
+            ..Default::default()
+        },
+        Some(tester.comments.clone())
+    ),
+    nested_component_in_component,
+    // Input codes
+    r#"
+    const OuterComponent = () => {
+        const InnerComponent = () => {
+            const value = useCustomValue();
+            return <div>{value}</div>;
+        };
+        return <InnerComponent />;
+    };
+    "#,
+    // Output
+    r#"
+    var __prevRefreshReg = global.$RefreshReg$;
+    var __prevRefreshSig = global.$RefreshSig$;
+    global.$RefreshReg$ = global.$RefreshRuntime$.getRegisterFunction();
+    global.$RefreshSig$ = global.$RefreshRuntime$.getCreateSignatureFunction();
+    var _s = global.$RefreshSig$();
+    const OuterComponent = () => {
+        const InnerComponent = () => {
+            _s();
+            const value = useCustomValue();
+            return <div>{value}</div>;
+        };
+        _s(InnerComponent, "test:OuterComponent/InnerComponent", true);
+        return <InnerComponent />;
+    };
+    global.$RefreshReg$(OuterComponent, "OuterComponent");
+    global.$RefreshRuntime$.getContext(OuterComponent).accept();
+    global.$RefreshRuntime$.registerModule?.("test", { exports: [], components: ["OuterComponent"], isBoundary: false });
+    global.$RefreshReg$ = __prevRefreshReg;
+    global.$RefreshSig$ = __prevRefreshSig;
+    "#
+);
+
+test!(
+    swc_ecma_parser::Syntax::Es(swc_ecma_parser::EsConfig {
+        jsx: true,
+        ..Default::default()
+    }),
+    |tester| react_refresh(
+        String::from("test"),
+        Default::default(),
+        Some(tester.comments.clone())
+    ),
+    nested_component_disabled,
+    // Input codes
+    r#"
+    export function createScreen() {
+        return function Screen() {
+            useEffect(() => {});
+            return <div />;
+        };
+    }
+    "#,
+    // Output
+    r#"
+    export function createScreen() {
+        return function Screen() {
+            useEffect(() => {});
+            return <div />;
+        };
+    }
+    "#
+);
+
+test!(
+    swc_ecma_parser::Syntax::Es(swc_ecma_parser::EsConfig {
+        jsx: true,
+        ..Default::default()
+    }),
+    |tester| react_refresh(
+        String::from("test"),
+        TransformOptions {
+            nested_components: true,
+            ..Default::default()
+        },
+        Some(tester.comments.clone())
+    ),
+    nested_component_with_same_name,
+    // Input codes
+    r#"
+    export function App() {
+        const [page, setPage] = useState(0);
+        return <div>{page}</div>;
+    }
+
+    export function createScreens() {
+        function createHome() {
+            function Screen() {
+                useEffect(() => {});
+                return <div />;
+            }
+            return Screen;
+        }
+
+        const createSettings = () => {
+            function Screen() {
+                useEffect(() => {});
+                return <div />;
+            }
+            return Screen;
+        };
+
+        return [createHome(), createSettings()];
+    }
+    "#,
+    // Output
+    r#"
+    var __prevRefreshReg = global.$RefreshReg$;
+    var __prevRefreshSig = global.$RefreshSig$;
+    global.$RefreshReg$ = global.$RefreshRuntime$.getRegisterFunction();
+    global.$RefreshSig$ = global.$RefreshRuntime$.getCreateSignatureFunction();
+    var __s = global.$RefreshSig$();
+    var _s = global.$RefreshSig$();
+    var _s2 = global.$RefreshSig$();
+    export function App() {
+        __s();
+        const [page, setPage] = useState(0);
+        return <div>{page}</div>;
+    }
+    export function createScreens() {
+        function createHome() {
+            function Screen() {
+                _s();
+                useEffect(() => {});
+                return <div />;
+            }
+            _s(Screen, "test:createScreens/createHome/Screen", false);
+            return Screen;
+        }
+        const createSettings = () => {
+            function Screen() {
+                _s2();
+                useEffect(() => {});
+                return <div />;
+            }
+            _s2(Screen, "test:createScreens/createSettings/Screen", false);
+            return Screen;
+        };
+        return [createHome(), createSettings()];
+    }
+    __s(App, "test:App", false);
+    global.$RefreshReg$(App, "App");
+    global.$RefreshRuntime$.getContext(App).accept();
+    global.$RefreshRuntime$.registerModule?.("test", { exports: ["App", "createScreens"], components: ["App"], isBoundary: false });
+    global.$RefreshReg$ = __prevRefreshReg;
+    global.$RefreshSig$ = __prevRefreshSig;
+    "#
+);
+
+test!(
+    swc_ecma_parser::Syntax::Es(swc_ecma_parser::EsConfig {
+        jsx: true,
+        ..Default::default()
+    }),
+    |tester| react_refresh(
+        String::from("test"),
+        TransformOptions {
+            nested_components: true,
+            ..Default::default()
+        },
+        Some(tester.comments.clone())
+    ),
+    nested_component_signature_name_conflict,
+    // Input codes
+    r#"
+    const _s = 1;
+
+    export function create() {
+        return function Screen() {
+            useState(_s);
+            return null;
+        };
+    }
+    "#,
+    // Output
+    r#"
+    var __prevRefreshReg = global.$RefreshReg$;
+    var __prevRefreshSig = global.$RefreshSig$;
+    global.$RefreshReg$ = global.$RefreshRuntime$.getRegisterFunction();
+    global.$RefreshSig$ = global.$RefreshRuntime$.getCreateSignatureFunction();
+    var _s2 = global.$RefreshSig$();
+    const _s = 1;
+    export function create() {
+        return _s2(function Screen() {
+            _s2();
+            useState(_s);
+            return null;
+        }, "test:create/Screen", false);
+    }
+    global.$RefreshRuntime$.registerModule?.("test", { exports: ["create"], components: [], isBoundary: false });
+    global.$RefreshReg$ = __prevRefreshReg;
+    global.$RefreshSig$ = __prevRefreshSig;
+    "#
+);
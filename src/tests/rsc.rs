@@ -0,0 +1,106 @@
+use super::react_refresh;
+use crate::options::TransformOptions;
+use swc_core::ecma::transforms::testing::test;
+
+test!(
+    swc_ecma_parser::Syntax::Es(swc_ecma_parser::EsConfig {
+        jsx: true,
+        ..Default::default()
+    }),
+    |tester| react_refresh(
+        String::from("test"),
+        Default::default(),
+        Some(tester.comments.clone())
+    ),
+    use_server_module,
+    // Input codes
+    r#"
+    'use server';
+
+    export async function ServerAction() {
+        return null;
+    }
+    "#,
+    // Output
+    r#"
+    'use server';
+    export async function ServerAction() {
+        return null;
+    }
+    "#
+);
+
+test!(
+    swc_ecma_parser::Syntax::Es(swc_ecma_parser::EsConfig {
+        jsx: true,
+        ..Default::default()
+    }),
+    |tester| react_refresh(
+        String::from("test"),
+        TransformOptions {
+            rsc: true,
+            ..Default::default()
+        },
+        Some(tester.comments.clone())
+    ),
+    rsc_server_component_module,
+    // Input codes
+    r#"
+    export default async function ServerComponent() {
+        const data = await fetchData();
+        return <div>{data}</div>;
+    }
+    "#,
+    // Output
+    r#"
+    export default async function ServerComponent() {
+        const data = await fetchData();
+        return <div>{data}</div>;
+    }
+    "#
+);
+
+test!(
+    swc_ecma_parser::Syntax::Es(swc_ecma_parser::EsConfig {
+        jsx: true,
+        ..Default::default()
+    }),
+    |tester| react_refresh(
+        String::from("test"),
+        TransformOptions {
+            rsc: true,
+            ..Default::default()
+        },
+        Some(tester.comments.clone())
+    ),
+    rsc_client_component_module,
+    // Input codes
+    r#"
+    'use client';
+
+    export function ClientComponent() {
+        const [number, setNumber] = useState(0);
+        return <div>{number}</div>;
+    }
+    "#,
+    // Output
+    r#"
+    'use client';
+    var __prevRefreshReg = global.$RefreshReg$;
+    var __prevRefreshSig = global.$RefreshSig$;
+    global.$RefreshReg$ = global.$RefreshRuntime$.getRegisterFunction();
+    global.$RefreshSig$ = global.$RefreshRuntime$.getCreateSignatureFunction();
+    var __s = global.$RefreshSig$();
+    export function ClientComponent() {
+        __s();
+        const [number, setNumber] = useState(0);
+        return <div>{number}</div>;
+    }
+    __s(ClientComponent, "test:ClientComponent", false);
+    global.$RefreshReg$(ClientComponent, "ClientComponent");
+    global.$RefreshRuntime$.getContext(ClientComponent).accept();
+    global.$RefreshRuntime$.registerModule?.("test", { exports: ["ClientComponent"], components: ["ClientComponent"], isBoundary: true });
+    global.$RefreshReg$ = __prevRefreshReg;
+    global.$RefreshSig$ = __prevRefreshSig;
+    "#
+);
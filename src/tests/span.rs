@@ -0,0 +1,115 @@
+use super::react_refresh;
+use swc_common::{Span, Spanned};
+use swc_core::ecma::{ast::*, transforms::testing::Tester, visit::VisitMut};
+
+/// Returns spans of the generated statements that call the callee.
+fn get_call_stmt_spans(module: &Module, callee_name: &str) -> Vec<Span> {
+    module
+        .body
+        .iter()
+        .filter_map(|module_item| module_item.as_stmt()?.as_expr())
+        .filter(|expr_stmt| {
+            let Some(callee) = expr_stmt
+                .expr
+                .as_call()
+                .and_then(|call_expr| call_expr.callee.as_expr())
+            else {
+                return false;
+            };
+            match &**callee {
+                Expr::Ident(ident) => &*ident.sym == callee_name,
+                Expr::Member(member_expr) => member_expr
+                    .prop
+                    .as_ident()
+                    .is_some_and(|ident| &*ident.sym == callee_name),
+                _ => false,
+            }
+        })
+        .map(|expr_stmt| expr_stmt.span)
+        .collect()
+}
+
+#[test]
+fn span_component_registration() {
+    let syntax = swc_ecma_parser::Syntax::Es(swc_ecma_parser::EsConfig {
+        jsx: true,
+        ..Default::default()
+    });
+
+    Tester::run(|tester| {
+        let mut module = tester.with_parser(
+            "input.js",
+            syntax,
+            r#"
+            export function Counter() {
+                const [count, setCount] = useState(0);
+                return <div>{count}</div>;
+            }
+
+            export const Title = () => <h1 />;
+            "#,
+            |parser| parser.parse_module(),
+        )?;
+
+        let counter_decl = module.body[0]
+            .as_module_decl()
+            .unwrap()
+            .as_export_decl()
+            .unwrap();
+        let counter_span = counter_decl.decl.as_fn_decl().unwrap().span();
+        let counter_body_span = counter_decl
+            .decl
+            .as_fn_decl()
+            .unwrap()
+            .function
+            .body
+            .as_ref()
+            .unwrap()
+            .span;
+        let title_span = module.body[1]
+            .as_module_decl()
+            .unwrap()
+            .as_export_decl()
+            .unwrap()
+            .decl
+            .as_var()
+            .unwrap()
+            .decls[0]
+            .span;
+
+        react_refresh(
+            String::from("test"),
+            Default::default(),
+            Some(tester.comments.clone()),
+        )
+        .visit_mut_module(&mut module);
+
+        // Signature and registrations are mapped to the component declaration.
+        assert_eq!(get_call_stmt_spans(&module, "__s"), vec![counter_span]);
+        assert_eq!(
+            get_call_stmt_spans(&module, "$RefreshReg$"),
+            vec![counter_span, title_span]
+        );
+        assert_eq!(
+            get_call_stmt_spans(&module, "accept"),
+            vec![counter_span, title_span]
+        );
+
+        // The prologue is mapped to the start of the function body.
+        let counter_fn = module
+            .body
+            .iter()
+            .find_map(|module_item| module_item.as_module_decl()?.as_export_decl())
+            .and_then(|export_decl| export_decl.decl.as_fn_decl())
+            .unwrap();
+        let prologue_span = counter_fn.function.body.as_ref().unwrap().stmts[0].span();
+        assert_eq!(prologue_span, counter_body_span.shrink_to_lo());
+
+        // Setup statements are not mapped.
+        assert!(module
+            .body
+            .iter()
+            .any(|module_item| module_item.span().is_dummy()));
+        Ok(())
+    });
+}
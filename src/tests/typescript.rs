@@ -0,0 +1,103 @@
+use super::react_refresh;
+use swc_core::ecma::transforms::testing::test;
+
+test!(
+    swc_ecma_parser::Syntax::Typescript(swc_ecma_parser::TsConfig {
+        tsx: true,
+        ..Default::default()
+    }),
+    |tester| react_refresh(
+        String::from("test"),
+        Default::default(),
+        Some(tester.comments.clone())
+    ),
+    typescript_wrapped_component,
+    // Input codes
+    r#"
+    const AsComponent = (function () {
+        const [number, setNumber] = useState(0);
+        return <div>{'Hello, World'}</div>;
+    }) as FC;
+    const SatisfiesComponent = (function (props) {
+        return <div>{props.title}</div>;
+    }) satisfies FC<Props>;
+    const NonNullComponent = (function () {
+        return <div>{'Hello, World'}</div>;
+    })!;
+    const ParenComponent = (() => {
+        return <div>{'Hello, World'}</div>;
+    });
+    "#,
+    // Output
+    r#"
+    var __prevRefreshReg = global.$RefreshReg$;
+    var __prevRefreshSig = global.$RefreshSig$;
+    global.$RefreshReg$ = global.$RefreshRuntime$.getRegisterFunction();
+    global.$RefreshSig$ = global.$RefreshRuntime$.getCreateSignatureFunction();
+    var __s = global.$RefreshSig$();
+    const AsComponent = function () {
+        __s();
+        const [number, setNumber] = useState(0);
+        return <div>{'Hello, World'}</div>;
+    } as FC;
+    const SatisfiesComponent = function (props) {
+        return <div>{props.title}</div>;
+    } satisfies FC<Props>;
+    const NonNullComponent = function () {
+        return <div>{'Hello, World'}</div>;
+    }!;
+    const ParenComponent = () => {
+        return <div>{'Hello, World'}</div>;
+    };
+    __s(AsComponent, "test:AsComponent", false);
+    global.$RefreshReg$(AsComponent, "AsComponent");
+    global.$RefreshRuntime$.getContext(AsComponent).accept();
+    global.$RefreshReg$(SatisfiesComponent, "SatisfiesComponent");
+    global.$RefreshRuntime$.getContext(SatisfiesComponent).accept();
+    global.$RefreshReg$(NonNullComponent, "NonNullComponent");
+    global.$RefreshRuntime$.getContext(NonNullComponent).accept();
+    global.$RefreshReg$(ParenComponent, "ParenComponent");
+    global.$RefreshRuntime$.getContext(ParenComponent).accept();
+    global.$RefreshRuntime$.registerModule?.("test", { exports: [], components: ["AsComponent", "SatisfiesComponent", "NonNullComponent", "ParenComponent"], isBoundary: false });
+    global.$RefreshReg$ = __prevRefreshReg;
+    global.$RefreshSig$ = __prevRefreshSig;
+    "#
+);
+
+test!(
+    swc_ecma_parser::Syntax::Typescript(Default::default()),
+    |tester| react_refresh(
+        String::from("test"),
+        Default::default(),
+        Some(tester.comments.clone())
+    ),
+    typescript_type_assertion_component,
+    // Input codes
+    r#"
+    export const AssertionComponent = <FC>(function () {
+        return React.createElement('div', null, 'Hello, World');
+    });
+    export const ConstComponent = (function () {
+        return React.createElement('div', null, 'Hello, World');
+    }) as const;
+    "#,
+    // Output
+    r#"
+    var __prevRefreshReg = global.$RefreshReg$;
+    var __prevRefreshSig = global.$RefreshSig$;
+    global.$RefreshReg$ = global.$RefreshRuntime$.getRegisterFunction();
+    export const AssertionComponent = <FC>function () {
+        return React.createElement('div', null, 'Hello, World');
+    };
+    export const ConstComponent = function () {
+        return React.createElement('div', null, 'Hello, World');
+    } as const;
+    global.$RefreshReg$(AssertionComponent, "AssertionComponent");
+    global.$RefreshRuntime$.getContext(AssertionComponent).accept();
+    global.$RefreshReg$(ConstComponent, "ConstComponent");
+    global.$RefreshRuntime$.getContext(ConstComponent).accept();
+    global.$RefreshRuntime$.registerModule?.("test", { exports: ["AssertionComponent", "ConstComponent"], components: ["AssertionComponent", "ConstComponent"], isBoundary: true });
+    global.$RefreshReg$ = __prevRefreshReg;
+    global.$RefreshSig$ = __prevRefreshSig;
+    "#
+);
@@ -0,0 +1,63 @@
+use super::react_refresh;
+use crate::options::TransformOptions;
+use std::sync::{Arc, Mutex};
+use swc_common::{
+    errors::{DiagnosticBuilder, Emitter, Handler, HANDLER},
+    SourceMapper, Span,
+};
+use swc_core::ecma::{transforms::testing::Tester, visit::FoldWith};
+
+/// Message and primary span of the reported diagnostic.
+type Message = (String, Option<Span>);
+
+/// Collects messages of the reported diagnostics.
+struct MessageCollector(Arc<Mutex<Vec<Message>>>);
+
+impl Emitter for MessageCollector {
+    fn emit(&mut self, db: &DiagnosticBuilder<'_>) {
+        self.0
+            .lock()
+            .unwrap()
+            .push((db.message(), db.span.primary_span()));
+    }
+}
+
+/// Returns warnings that are reported while transforming the code.
+pub fn get_warnings(code: &str, options: TransformOptions) -> Vec<String> {
+    get_warnings_with_source(code, options)
+        .into_iter()
+        .map(|(message, _)| message)
+        .collect()
+}
+
+/// Returns warnings with the source code of the reported span.
+pub fn get_warnings_with_source(code: &str, options: TransformOptions) -> Vec<(String, String)> {
+    let messages = Arc::new(Mutex::new(Vec::new()));
+    let mut warnings = Vec::new();
+    let handler = Handler::with_emitter(true, false, Box::new(MessageCollector(messages.clone())));
+    let syntax = swc_ecma_parser::Syntax::Es(swc_ecma_parser::EsConfig {
+        jsx: true,
+        ..Default::default()
+    });
+
+    Tester::run(|tester| {
+        let module =
+            tester.with_parser("input.js", syntax, code, |parser| parser.parse_module())?;
+        HANDLER.set(&handler, || {
+            module.fold_with(&mut react_refresh(
+                String::from("test"),
+                options,
+                Some(tester.comments.clone()),
+            ))
+        });
+        for (message, span) in messages.lock().unwrap().iter() {
+            let source = span
+                .and_then(|span| tester.cm.span_to_snippet(span).ok())
+                .unwrap_or_default();
+            warnings.push((message.to_owned(), source));
+        }
+        Ok(())
+    });
+
+    warnings
+}
@@ -1,16 +1,27 @@
 use crate::{
+    options::TransformOptions,
+    report::{ComponentReport, RefreshReport},
     utils::{
-        arg_expr, assign_expr, bool_expr, call_expr, decl_var_and_assign_stmt, get_name_from_ident,
-        ident, ident_expr, ident_str_expr, is_componentish_name, obj_prop_expr, str_expr, to_stmt,
+        arg_expr, array_expr, arrow_expr, assign_expr, bool_expr, call_expr, call_expr_with_span,
+        decl_var_and_assign_stmt, directive_count, emit_warning, get_callee_name,
+        get_declared_idents, get_import_meta_hot_dispose_handler, get_name_from_ident,
+        has_directive, has_leading_comment, ident, ident_expr, ident_str_expr,
+        is_componentish_name, is_create_context_call, is_exports_ident, is_hook_name, is_lazy_call,
+        is_module_exports, obj_prop_expr, object_expr, opt_call_expr, str_expr, to_stmt,
+        to_stmt_with_span, unwrap_expr,
     },
-    visitor,
+    visitor::{self, ExportMeta, HookImports},
 };
+use regex::Regex;
 use std::collections::HashSet;
-use swc_common::Span;
+use swc_common::{comments::Comments, util::take::Take, Span, Spanned};
 use swc_core::ecma::{
     ast::*,
     atoms::{js_word, Atom},
-    visit::{noop_fold_type, Fold, FoldWith, VisitWith},
+    visit::{
+        as_folder, noop_visit_mut_type, noop_visit_type, Fold, Visit, VisitMut, VisitMutWith,
+        VisitWith,
+    },
 };
 
 const GLOBAL: &str = "global";
@@ -20,12 +31,29 @@ const RUNTIME_REF: &str = "$RefreshRuntime$";
 const RUNTIME_GET_REGISTER_FN: &str = "getRegisterFunction";
 const RUNTIME_GET_SIGNATURE_FN: &str = "getCreateSignatureFunction";
 const RUNTIME_GET_CONTEXT_FN: &str = "getContext";
+const RUNTIME_REGISTER_MODULE_FN: &str = "registerModule";
+const RUNTIME_BEGIN_MODULE_FN: &str = "beginModule";
+const RUNTIME_END_MODULE_FN: &str = "endModule";
+const RUNTIME_ADD_DISPOSE_HANDLER_FN: &str = "addDisposeHandler";
+const RUNTIME_GET_OR_CREATE_CONTEXT_FN: &str = "getOrCreateContext";
+const RUNTIME_GET_PERSISTED_VALUE_FN: &str = "getPersistedValue";
 const CONTEXT_ACCEPT_FN: &str = "accept";
 const TEMP_REGISTER_REF: &str = "__prevRefreshReg";
 const TEMP_SIGNATURE_REF: &str = "__prevRefreshSig";
 const SIGNATURE_FN: &str = "__s";
+const NESTED_SIGNATURE_FN: &str = "_s";
+const DISPOSE_HANDLER: &str = "__refreshDispose";
+const REFRESH_RESET_DIRECTIVE: &str = "@refresh reset";
+const REFRESH_PERSIST_DIRECTIVE: &str = "@refresh persist";
+const REFRESH_DISABLE_DIRECTIVE: &str = "@refresh-disable";
+const USE_NO_REFRESH_DIRECTIVE: &str = "use no refresh";
+const USE_CLIENT_DIRECTIVE: &str = "use client";
+const USE_SERVER_DIRECTIVE: &str = "use server";
+const USE_HOOK: &str = "use";
+
+const HOOK_SOURCES: &[&str] = &["react", "preact/hooks"];
 
-const BUILTIN_HOOKS: &'static [&'static str] = &[
+const BUILTIN_HOOKS: &[&str] = &[
     "useState",
     "useReducer",
     "useEffect",
@@ -36,37 +64,169 @@ const BUILTIN_HOOKS: &'static [&'static str] = &[
     "useContext",
     "useImperativeHandle",
     "useDebugValue",
+    "useId",
+    "useTransition",
+    "useDeferredValue",
+    "useSyncExternalStore",
+    "useInsertionEffect",
+    "useOptimistic",
+    "useActionState",
+    "useFormStatus",
+    "use",
 ];
 
+/// Decide whether the called function is a hook.
+struct HookMatcher {
+    builtin_hooks: HashSet<String>,
+    hook_pattern: Option<Regex>,
+    hook_sources: HashSet<String>,
+    /// Hooks imported from the hook sources in the current module.
+    imports: HookImports,
+}
+
+impl HookMatcher {
+    fn new(options: &TransformOptions) -> HookMatcher {
+        // `builtinHooks` replaces the default list and `additionalBuiltinHooks` extends it.
+        let builtin_hooks = match &options.builtin_hooks {
+            Some(builtin_hooks) => builtin_hooks.to_owned(),
+            None => BUILTIN_HOOKS.iter().map(|hook| hook.to_string()).collect(),
+        }
+        .into_iter()
+        .chain(options.additional_builtin_hooks.iter().cloned())
+        .collect();
+
+        let hook_pattern = options.hook_pattern.as_ref().map(|hook_pattern| {
+            Regex::new(hook_pattern).expect("invalid `hookPattern` for swc-plugin-react-refresh")
+        });
+
+        let hook_sources = HOOK_SOURCES
+            .iter()
+            .map(|hook_source| hook_source.to_string())
+            .chain(options.additional_hook_sources.iter().cloned())
+            .collect();
+
+        HookMatcher {
+            builtin_hooks,
+            hook_pattern,
+            hook_sources,
+            imports: HookImports::default(),
+        }
+    }
+
+    /// Returns the imported name if the identifier is imported from the hook sources.
+    ///
+    /// Code: `import { useState as useS } from 'react';`
+    fn resolve_imported_name(&self, ident: &Ident) -> Option<&str> {
+        self.imports.named.get(&*ident.sym).map(String::as_str)
+    }
+
+    /// Returns the hook name of the callee and whether it is imported from the hook sources.
+    ///
+    /// Returns `None` when the callee is not a hook.
+    ///
+    /// - `useState()`, `useS()` (Aliases are resolved to the imported name)
+    /// - `React.useState()` (Only the property name is checked)
+    fn resolve_hook_call<'b>(&'b self, call_expr: &'b CallExpr) -> Option<(&'b str, bool)> {
+        let (hook_name, is_imported) = match &**call_expr.callee.as_expr()? {
+            Expr::Ident(ident) => match self.resolve_imported_name(ident) {
+                Some(imported_name) => (imported_name, true),
+                None => (&*ident.sym, false),
+            },
+            Expr::Member(member_expr) => (
+                &*member_expr.prop.as_ident()?.sym,
+                self.is_imported_namespace(&member_expr.obj),
+            ),
+            _ => return None,
+        };
+
+        (self.is_builtin_hook(hook_name) || self.is_hook(hook_name))
+            .then_some((hook_name, is_imported))
+    }
+
+    /// Returns `true` if the object is imported from the hook sources.
+    ///
+    /// Code: `import * as R from 'react';`
+    fn is_imported_namespace(&self, obj: &Expr) -> bool {
+        obj.as_ident()
+            .is_some_and(|ident| self.imports.namespaces.contains(&*ident.sym))
+    }
+
+    fn is_builtin_hook(&self, name: &str) -> bool {
+        self.builtin_hooks.contains(name)
+    }
+
+    fn is_hook(&self, name: &str) -> bool {
+        match &self.hook_pattern {
+            Some(hook_pattern) => hook_pattern.is_match(name),
+            None => is_hook_name(name),
+        }
+    }
+}
+
 struct ComponentMeta {
-    span: Span,
     name: String,
+    /// Expression that references the component (eg. `Component`, `exports.Component`).
+    reference: Expr,
+    /// Span of the component declaration.
+    ///
+    /// Generated signature and registration of the component are mapped to it.
+    span: Span,
     builtin_hook_count: i32,
     custom_hook_count: i32,
+    /// Names of hooks that are called by the component in order.
+    hooks: Vec<String>,
+    /// Names of custom hooks that are called by the component.
+    custom_hooks: Vec<String>,
+    force_reset: bool,
+    /// `React.lazy` wrapper is registered only. (No signature and accept)
+    is_lazy: bool,
+    /// Reassigned through HOCs after declaration. (eg. `Component = memo(Component);`)
+    ///
+    /// The signature is added to the original value before the first reassignment.
+    is_reassigned: bool,
 }
 
 /// For add the empty signature function call expression into React component
 /// and check if any custom hooks are used.
-struct ReactRefreshRuntimeComponent {
+struct ReactRefreshRuntimeComponent<'a> {
+    hook_matcher: &'a HookMatcher,
+    /// Name of the signature function variable. (eg. `__s`)
+    signature_ref: String,
     is_empty: bool,
     builtin_hook_count: i32,
     custom_hook_count: i32,
+    hooks: Vec<String>,
+    custom_hooks: Vec<String>,
 }
 
-impl ReactRefreshRuntimeComponent {
-    fn default() -> ReactRefreshRuntimeComponent {
+impl<'a> ReactRefreshRuntimeComponent<'a> {
+    fn default(hook_matcher: &'a HookMatcher) -> ReactRefreshRuntimeComponent<'a> {
+        ReactRefreshRuntimeComponent::with_signature_ref(hook_matcher, String::from(SIGNATURE_FN))
+    }
+
+    fn with_signature_ref(
+        hook_matcher: &'a HookMatcher,
+        signature_ref: String,
+    ) -> ReactRefreshRuntimeComponent<'a> {
         ReactRefreshRuntimeComponent {
+            hook_matcher,
+            signature_ref,
             is_empty: false,
             builtin_hook_count: 0,
             custom_hook_count: 0,
+            hooks: Vec::new(),
+            custom_hooks: Vec::new(),
         }
     }
 
     /// Returns a statement that call the signature function without arguments.
     ///
     /// Code: `__s();`
-    fn get_signature_call_stmt(&self) -> Stmt {
-        to_stmt(call_expr(ident_expr(js_word!(SIGNATURE_FN)), vec![]))
+    fn get_signature_call_stmt(&self, span: Span) -> Stmt {
+        to_stmt_with_span(
+            call_expr_with_span(ident_expr(self.signature_ref.as_str().into()), vec![], span),
+            span,
+        )
     }
 
     fn find_hook_call_from_stmt(&mut self, stmt: &Stmt) {
@@ -92,38 +252,24 @@ impl ReactRefreshRuntimeComponent {
     }
 
     /// Find hooks from call expression.
+    ///
+    /// Hooks imported from the hook sources are treated as builtin hooks.
     fn find_hook(&mut self, call_expr: &CallExpr) {
-        if let Some(callee_expr) = call_expr.callee.as_expr() {
-            match *callee_expr.to_owned() {
-                // useXXX()
-                Expr::Ident(ident) => {
-                    self.count_hook(&ident);
-                }
-                // React.useXXX()
-                Expr::Member(member_expr) => {
-                    if let Some(ident) = &member_expr.prop.ident() {
-                        self.count_hook(ident);
-                    }
-                }
-                _ => (),
+        if let Some((hook_name, is_imported)) = self.hook_matcher.resolve_hook_call(call_expr) {
+            if is_imported || self.hook_matcher.is_builtin_hook(hook_name) {
+                self.builtin_hook_count += 1;
+            } else {
+                self.custom_hook_count += 1;
+                self.custom_hooks.push(hook_name.to_owned());
             }
-        }
-    }
-
-    /// Count hooks
-    fn count_hook(&mut self, ident: &Ident) {
-        let hook_name = ident.sym.to_string();
-        if BUILTIN_HOOKS.contains(&hook_name.as_str()) {
-            self.builtin_hook_count += 1;
-        } else if hook_name.starts_with("use") {
-            self.custom_hook_count += 1;
+            self.hooks.push(hook_name.to_owned());
         }
     }
 }
 
-impl Fold for ReactRefreshRuntimeComponent {
-    fn fold_block_stmt(&mut self, mut block_stmt: BlockStmt) -> BlockStmt {
-        self.is_empty = block_stmt.stmts.len() == 0;
+impl VisitMut for ReactRefreshRuntimeComponent<'_> {
+    fn visit_mut_block_stmt(&mut self, block_stmt: &mut BlockStmt) {
+        self.is_empty = block_stmt.stmts.is_empty();
 
         for stmt in block_stmt.stmts.iter() {
             // Explore all of function call statements.
@@ -132,80 +278,760 @@ impl Fold for ReactRefreshRuntimeComponent {
 
         // If no hook call found, do nothing.
         if self.builtin_hook_count + self.custom_hook_count == 0 {
-            return block_stmt;
+            return;
         }
 
         // Add `__s();` at the top inside the component.
         //
         // In `react-refresh/runtime` comment,
         // it says calling `__s()` without arguments will trigger to collect hooks.
-        block_stmt.stmts.insert(0, self.get_signature_call_stmt());
-        block_stmt
+        // (Mapped to the start of the function body)
+        block_stmt.stmts.insert(
+            0,
+            self.get_signature_call_stmt(block_stmt.span.shrink_to_lo()),
+        );
+    }
+}
+
+/// For report hooks that are called conditionally, in loops or after an early return.
+///
+/// Only the first function (= component) is inspected.
+/// Nested functions such as callbacks are not inspected.
+struct HookCallLinter<'a> {
+    hook_matcher: &'a HookMatcher,
+    function_depth: usize,
+    conditional_depth: usize,
+    loop_depth: usize,
+    has_early_return: bool,
+}
+
+impl<'a> HookCallLinter<'a> {
+    fn default(hook_matcher: &'a HookMatcher) -> HookCallLinter<'a> {
+        HookCallLinter {
+            hook_matcher,
+            function_depth: 0,
+            conditional_depth: 0,
+            loop_depth: 0,
+            has_early_return: false,
+        }
+    }
+
+    fn visit_conditional<N: VisitWith<Self>>(&mut self, node: &N) {
+        self.conditional_depth += 1;
+        node.visit_with(self);
+        self.conditional_depth -= 1;
+    }
+
+    fn visit_loop<N: VisitWith<Self>>(&mut self, node: &N) {
+        self.loop_depth += 1;
+        node.visit_children_with(self);
+        self.loop_depth -= 1;
+    }
+
+    fn visit_component<N: VisitWith<Self>>(&mut self, node: &N) {
+        // Skip nested functions.
+        if self.function_depth > 0 {
+            return;
+        }
+        self.function_depth += 1;
+        node.visit_children_with(self);
+        self.function_depth -= 1;
+    }
+}
+
+impl Visit for HookCallLinter<'_> {
+    noop_visit_type!();
+
+    fn visit_function(&mut self, function: &Function) {
+        self.visit_component(function);
+    }
+
+    fn visit_arrow_expr(&mut self, arrow_expr: &ArrowExpr) {
+        self.visit_component(arrow_expr);
+    }
+
+    fn visit_class(&mut self, _: &Class) {}
+
+    fn visit_if_stmt(&mut self, if_stmt: &IfStmt) {
+        if_stmt.test.visit_with(self);
+        self.visit_conditional(&if_stmt.cons);
+        self.visit_conditional(&if_stmt.alt);
+    }
+
+    fn visit_switch_stmt(&mut self, switch_stmt: &SwitchStmt) {
+        switch_stmt.discriminant.visit_with(self);
+        self.visit_conditional(&switch_stmt.cases);
+    }
+
+    fn visit_cond_expr(&mut self, cond_expr: &CondExpr) {
+        cond_expr.test.visit_with(self);
+        self.visit_conditional(&cond_expr.cons);
+        self.visit_conditional(&cond_expr.alt);
+    }
+
+    fn visit_bin_expr(&mut self, bin_expr: &BinExpr) {
+        bin_expr.left.visit_with(self);
+        match bin_expr.op {
+            BinaryOp::LogicalAnd | BinaryOp::LogicalOr | BinaryOp::NullishCoalescing => {
+                self.visit_conditional(&bin_expr.right);
+            }
+            _ => bin_expr.right.visit_with(self),
+        }
+    }
+
+    fn visit_for_stmt(&mut self, for_stmt: &ForStmt) {
+        self.visit_loop(for_stmt);
+    }
+
+    fn visit_for_in_stmt(&mut self, for_in_stmt: &ForInStmt) {
+        self.visit_loop(for_in_stmt);
+    }
+
+    fn visit_for_of_stmt(&mut self, for_of_stmt: &ForOfStmt) {
+        self.visit_loop(for_of_stmt);
+    }
+
+    fn visit_while_stmt(&mut self, while_stmt: &WhileStmt) {
+        self.visit_loop(while_stmt);
+    }
+
+    fn visit_do_while_stmt(&mut self, do_while_stmt: &DoWhileStmt) {
+        self.visit_loop(do_while_stmt);
+    }
+
+    fn visit_return_stmt(&mut self, return_stmt: &ReturnStmt) {
+        return_stmt.visit_children_with(self);
+        self.has_early_return = true;
+    }
+
+    fn visit_call_expr(&mut self, call_expr: &CallExpr) {
+        let hook_name = self
+            .hook_matcher
+            .resolve_hook_call(call_expr)
+            .map(|(hook_name, _)| hook_name)
+            // `use` can be called conditionally and in loops. (Aliases are resolved to `use`)
+            .filter(|hook_name| *hook_name != USE_HOOK);
+
+        if let Some(hook_name) = hook_name {
+            if self.loop_depth > 0 {
+                emit_warning(
+                    call_expr.span,
+                    &format!(
+                        "React Hook \"{}\" may be executed more than once. Possibly because it is called in a loop.",
+                        hook_name
+                    ),
+                );
+            } else if self.conditional_depth > 0 {
+                emit_warning(
+                    call_expr.span,
+                    &format!(
+                        "React Hook \"{}\" is called conditionally. React Hooks must be called in the exact same order in every component render.",
+                        hook_name
+                    ),
+                );
+            } else if self.has_early_return {
+                emit_warning(
+                    call_expr.span,
+                    &format!(
+                        "React Hook \"{}\" is called after an early return. React Hooks must be called in the exact same order in every component render.",
+                        hook_name
+                    ),
+                );
+            }
+        }
+        call_expr.visit_children_with(self);
+    }
+}
+
+/// Check provided call expression calls the runtime function.
+///
+/// Code: `global.$RefreshRuntime$.fn_name()`
+fn is_runtime_call(call_expr: &CallExpr, fn_name: &str) -> bool {
+    let is_prop = |member_expr: &MemberExpr, name: &str| {
+        member_expr
+            .prop
+            .as_ident()
+            .is_some_and(|ident| &*ident.sym == name)
+    };
+
+    call_expr
+        .callee
+        .as_expr()
+        .and_then(|callee_expr| callee_expr.as_member())
+        .filter(|fn_member| is_prop(fn_member, fn_name))
+        .and_then(|fn_member| fn_member.obj.as_member())
+        .filter(|runtime_member| is_prop(runtime_member, RUNTIME_REF))
+        .is_some_and(|runtime_member| {
+            runtime_member
+                .obj
+                .as_ident()
+                .is_some_and(|ident| &*ident.sym == GLOBAL)
+        })
+}
+
+/// For find the runtime function calls that are generated by previous transform.
+struct RuntimeCallFinder<'a> {
+    fn_name: &'a str,
+    is_found: bool,
+}
+
+impl Visit for RuntimeCallFinder<'_> {
+    noop_visit_type!();
+
+    fn visit_call_expr(&mut self, call_expr: &CallExpr) {
+        if is_runtime_call(call_expr, self.fn_name) {
+            self.is_found = true;
+            return;
+        }
+        call_expr.visit_children_with(self);
+    }
+}
+
+/// Returns an expression that adds the dispose handler of the module.
+///
+/// The runtime returns the handler that runs only once.
+///
+/// Code: `global.$RefreshRuntime$.addDisposeHandler("module_id", handler)`
+fn get_add_dispose_handler_expr(module_id: &str, handler: Expr) -> Expr {
+    call_expr(
+        obj_prop_expr(
+            obj_prop_expr(ident_expr(js_word!(GLOBAL)), ident(js_word!(RUNTIME_REF))),
+            ident(js_word!(RUNTIME_ADD_DISPOSE_HANDLER_FN)),
+        ),
+        vec![arg_expr(str_expr(module_id)), arg_expr(handler)],
+    )
+}
+
+/// For register handlers of `import.meta.hot.dispose()` to the runtime.
+///
+/// The handler is evaluated once and passed through the runtime,
+/// so the bundler and the runtime share the same handler that runs only once.
+/// Calls inside functions are not supported.
+///
+/// ```js
+/// if (import.meta.hot) {
+///   import.meta.hot.dispose(global.$RefreshRuntime$.addDisposeHandler("module_id", handler));
+/// }
+/// ```
+struct DisposeCallRegistrar<'a> {
+    module_id: &'a str,
+}
+
+impl VisitMut for DisposeCallRegistrar<'_> {
+    noop_visit_mut_type!();
+
+    fn visit_mut_function(&mut self, _: &mut Function) {}
+
+    fn visit_mut_arrow_expr(&mut self, _: &mut ArrowExpr) {}
+
+    fn visit_mut_class(&mut self, _: &mut Class) {}
+
+    fn visit_mut_expr(&mut self, expr: &mut Expr) {
+        expr.visit_mut_children_with(self);
+
+        // - `import.meta.hot.dispose(handler)`
+        // - `import.meta.hot?.dispose(handler)`
+        if let Some(handler) = get_import_meta_hot_dispose_handler(expr) {
+            **handler = get_add_dispose_handler_expr(self.module_id, *handler.take());
+        }
+    }
+}
+
+/// Returns an expression that call the signature function.
+///
+/// Code: `__s(Component, "module_id:Component", false)`
+fn get_signature_call_expr(
+    signature_ref: &str,
+    reference: Expr,
+    id: &str,
+    force_reset: bool,
+    span: Span,
+) -> Expr {
+    call_expr_with_span(
+        ident_expr(signature_ref.into()),
+        vec![
+            arg_expr(reference),
+            arg_expr(str_expr(id)),
+            arg_expr(bool_expr(force_reset)),
+        ],
+        span,
+    )
+}
+
+/// Returns the name of the signature function variable for the nested component.
+///
+/// Same as babel plugin: `_s`, `_s2`, `_s3`, ...
+fn get_nested_signature_ref(index: usize) -> String {
+    if index == 1 {
+        String::from(NESTED_SIGNATURE_FN)
+    } else {
+        format!("{}{}", NESTED_SIGNATURE_FN, index)
+    }
+}
+
+/// For add signatures to the components that are declared inside top-level functions.
+///
+/// Each component has its own signature function. (Declared at the top of the module)
+///
+/// ```js
+/// export function createScreen() {
+///   function Screen() {
+///     _s();
+///     const [number, setNumber] = useState(0);
+///   }
+///   _s(Screen, "module_id:createScreen/Screen", false);
+///   return _s2(function Modal() {
+///     _s2();
+///     useEffect(() => {});
+///   }, "module_id:createScreen/Modal", false);
+/// }
+/// ```
+struct NestedComponentSigner<'a> {
+    hook_matcher: &'a HookMatcher,
+    /// Signature id prefix. (eg. `module_id:createScreen`)
+    id_prefix: String,
+    /// Names of the functions that are currently visited. (eg. `["renderList", "Item"]`)
+    scope_names: Vec<String>,
+    force_reset: bool,
+    /// Depth of the functions that are currently visited.
+    depth: usize,
+    /// Names that are used in the module. (Not available for the signature functions)
+    used_names: &'a HashSet<String>,
+    /// Signature functions that are allocated in the module. (eg. `["_s", "_s2"]`)
+    signature_refs: Vec<String>,
+}
+
+impl<'a> NestedComponentSigner<'a> {
+    fn default(
+        hook_matcher: &'a HookMatcher,
+        id_prefix: String,
+        force_reset: bool,
+        used_names: &'a HashSet<String>,
+        signature_refs: Vec<String>,
+    ) -> NestedComponentSigner<'a> {
+        NestedComponentSigner {
+            hook_matcher,
+            id_prefix,
+            scope_names: Vec::new(),
+            force_reset,
+            depth: 0,
+            used_names,
+            signature_refs,
+        }
+    }
+
+    /// Returns the next signature function name that is not used in the module.
+    ///
+    /// Same as `generateUidIdentifier` of babel plugin: `_s`, `_s2`, `_s3`, ...
+    fn get_next_signature_ref(&self) -> String {
+        (1..)
+            .map(get_nested_signature_ref)
+            .find(|signature_ref| {
+                !self.used_names.contains(signature_ref)
+                    && !self.signature_refs.contains(signature_ref)
+            })
+            .unwrap()
+    }
+
+    /// Add `_s();` into the component if the component uses hooks.
+    ///
+    /// Returns a function that makes the signature call expression when signed.
+    fn sign<T: for<'b> VisitMutWith<ReactRefreshRuntimeComponent<'b>> + Spanned>(
+        &mut self,
+        node: &mut T,
+        component_name: &str,
+    ) -> Option<impl FnOnce(Expr) -> Expr> {
+        let signature_ref = self.get_next_signature_ref();
+        let component = &mut ReactRefreshRuntimeComponent::with_signature_ref(
+            self.hook_matcher,
+            signature_ref.to_owned(),
+        );
+        node.visit_mut_children_with(component);
+
+        if component.builtin_hook_count + component.custom_hook_count == 0 {
+            return None;
+        }
+
+        self.signature_refs.push(signature_ref.to_owned());
+        // Includes all of the parent names to avoid conflicts.
+        // (eg. `module_id:createScreen/renderList/Item`)
+        let id = std::iter::once(self.id_prefix.as_str())
+            .chain(self.scope_names.iter().map(String::as_str))
+            .chain(std::iter::once(component_name))
+            .collect::<Vec<&str>>()
+            .join("/");
+        let force_reset = component.custom_hook_count > 0 || self.force_reset;
+        let span = node.span();
+        Some(move |reference: Expr| {
+            get_signature_call_expr(&signature_ref, reference, &id, force_reset, span)
+        })
+    }
+
+    /// Visit children of the named function with its name in scope.
+    ///
+    /// The top-level function is already a part of the id prefix.
+    fn visit_mut_named_function<T: VisitMutWith<Self>>(&mut self, node: &mut T, name: String) {
+        if self.depth == 0 {
+            node.visit_mut_children_with(self);
+            return;
+        }
+
+        self.scope_names.push(name);
+        node.visit_mut_children_with(self);
+        self.scope_names.pop();
+    }
+}
+
+impl VisitMut for NestedComponentSigner<'_> {
+    noop_visit_mut_type!();
+
+    fn visit_mut_fn_decl(&mut self, fn_decl: &mut FnDecl) {
+        let name = get_name_from_ident(&fn_decl.ident);
+        self.visit_mut_named_function(fn_decl, name);
+    }
+
+    fn visit_mut_fn_expr(&mut self, fn_expr: &mut FnExpr) {
+        match &fn_expr.ident {
+            Some(fn_ident) => {
+                let name = get_name_from_ident(fn_ident);
+                self.visit_mut_named_function(fn_expr, name);
+            }
+            None => fn_expr.visit_mut_children_with(self),
+        }
+    }
+
+    fn visit_mut_var_declarator(&mut self, var_declarator: &mut VarDeclarator) {
+        // - `const renderList = () => {};`
+        // - `const renderList = function () {};`
+        let name = var_declarator
+            .name
+            .as_ident()
+            .filter(|_| {
+                matches!(
+                    var_declarator.init.as_deref(),
+                    Some(Expr::Arrow(_) | Expr::Fn(FnExpr { ident: None, .. }))
+                )
+            })
+            .map(|binding| get_name_from_ident(&binding.id));
+
+        match name {
+            Some(name) => self.visit_mut_named_function(var_declarator, name),
+            None => var_declarator.visit_mut_children_with(self),
+        }
+    }
+
+    fn visit_mut_function(&mut self, function: &mut Function) {
+        self.depth += 1;
+        function.visit_mut_children_with(self);
+        self.depth -= 1;
+    }
+
+    fn visit_mut_arrow_expr(&mut self, arrow_expr: &mut ArrowExpr) {
+        self.depth += 1;
+        arrow_expr.visit_mut_children_with(self);
+        self.depth -= 1;
+    }
+
+    fn visit_mut_stmts(&mut self, stmts: &mut Vec<Stmt>) {
+        if self.depth == 0 {
+            stmts.visit_mut_children_with(self);
+            return;
+        }
+
+        // Sign the components before visit its children
+        // to allocate the signature functions in source order.
+        let mut signature_stmts = Vec::new();
+        for (index, stmt) in stmts.iter_mut().enumerate() {
+            match stmt {
+                // - `function Component() {}`
+                Stmt::Decl(Decl::Fn(fn_decl)) if is_componentish_name(&fn_decl.ident.sym) => {
+                    let component_name = get_name_from_ident(&fn_decl.ident);
+                    let reference = Expr::Ident(fn_decl.ident.to_owned());
+                    if let Some(signature_call_expr) = self.sign(fn_decl, &component_name) {
+                        let signature_stmt =
+                            to_stmt_with_span(signature_call_expr(reference), fn_decl.span());
+                        signature_stmts.push((index, signature_stmt));
+                    }
+                }
+                // - `const Component = () => {};`
+                // - `const Component = function () {};`
+                Stmt::Decl(Decl::Var(var_decl)) => {
+                    for var_declarator in var_decl.decls.iter_mut() {
+                        let Some(binding) = var_declarator.name.as_ident() else {
+                            continue;
+                        };
+                        // Named function expressions are signed by `visit_mut_expr`.
+                        let is_component = is_componentish_name(&binding.id.sym)
+                            && matches!(
+                                var_declarator.init.as_deref(),
+                                Some(Expr::Arrow(_) | Expr::Fn(FnExpr { ident: None, .. }))
+                            );
+                        if !is_component {
+                            continue;
+                        }
+
+                        let component_name = get_name_from_ident(&binding.id);
+                        let reference = Expr::Ident(binding.id.to_owned());
+                        if let Some(signature_call_expr) =
+                            self.sign(var_declarator, &component_name)
+                        {
+                            let signature_stmt = to_stmt_with_span(
+                                signature_call_expr(reference),
+                                var_declarator.span,
+                            );
+                            signature_stmts.push((index, signature_stmt));
+                        }
+                    }
+                }
+                _ => (),
+            }
+            stmt.visit_mut_with(self);
+        }
+
+        // Insert signatures after each declaration. (From the back to keep the indexes)
+        for (index, signature_stmt) in signature_stmts.into_iter().rev() {
+            stmts.insert(index + 1, signature_stmt);
+        }
+    }
+
+    fn visit_mut_expr(&mut self, expr: &mut Expr) {
+        // - `return function Component() {};`
+        let signature_call_expr = match expr {
+            Expr::Fn(FnExpr {
+                ident: Some(fn_ident),
+                function,
+            }) if self.depth > 0 && is_componentish_name(&fn_ident.sym) => {
+                let component_name = get_name_from_ident(fn_ident);
+                self.sign(function, &component_name)
+            }
+            _ => None,
+        };
+
+        expr.visit_mut_children_with(self);
+
+        if let Some(signature_call_expr) = signature_call_expr {
+            // `_s(function Component() {}, "module_id:parent/Component", false)`
+            *expr = signature_call_expr(expr.take());
+        }
     }
 }
 
 /// Find React components from module.
 /// And then add signature, register components and accept for HMR.
-pub struct ReactRefreshRuntime {
+pub struct ReactRefreshRuntime<C: Comments> {
     module_id: String,
+    options: TransformOptions,
+    comments: Option<C>,
+    force_reset: bool,
     module_body: Vec<ModuleItem>,
     component_list: Vec<ComponentMeta>,
     component_names: HashSet<String>,
     black_list: HashSet<String>,
+    exports: Vec<ExportMeta>,
+    /// Signature functions for nested components. (`_s`, `_s2`, ...)
+    nested_signature_refs: Vec<String>,
+    /// Names that are used in the module. (Only collected for nested components)
+    used_names: HashSet<String>,
+    /// `true` when dispose handlers are already registered by previous transform.
+    has_dispose_registration: bool,
+    hook_matcher: HookMatcher,
 }
 
-impl ReactRefreshRuntime {
-    fn default(module_id: String) -> ReactRefreshRuntime {
+impl<C: Comments> ReactRefreshRuntime<C> {
+    fn default(
+        module_id: String,
+        options: TransformOptions,
+        comments: Option<C>,
+    ) -> ReactRefreshRuntime<C> {
+        let hook_matcher = HookMatcher::new(&options);
+
         ReactRefreshRuntime {
             module_id,
+            options,
+            comments,
+            force_reset: false,
             module_body: Vec::new(),
             component_list: Vec::new(),
             component_names: HashSet::new(),
             black_list: HashSet::new(),
+            exports: Vec::new(),
+            nested_signature_refs: Vec::new(),
+            used_names: HashSet::new(),
+            has_dispose_registration: false,
+            hook_matcher,
         }
     }
 
-    fn initialize_before_fold_module(&mut self) {
+    fn initialize_before_visit_module(&mut self) {
+        self.force_reset = false;
         self.module_body.clear();
         self.component_list.clear();
         self.component_names.clear();
         self.black_list.clear();
+        self.exports.clear();
+        self.nested_signature_refs.clear();
+        self.used_names.clear();
     }
 
-    fn prepare_before_fold_module(&mut self, module: &Module) {
-        let mut collector = visitor::black_list_collector();
-        module.visit_with(&mut collector);
+    fn prepare_before_visit_module(&mut self, module_items: &[ModuleItem]) {
+        let mut collector = visitor::black_list_collector(&self.comments);
+        module_items.visit_with(&mut collector);
         self.black_list = collector.get_black_list();
+
+        let mut collector = visitor::export_collector();
+        module_items.visit_with(&mut collector);
+        self.exports = collector.get_exports();
+
+        let mut collector = visitor::hook_import_collector(&self.hook_matcher.hook_sources);
+        module_items.visit_with(&mut collector);
+        self.hook_matcher.imports = collector.get_imports();
+
+        if self.options.diagnostics {
+            module_items.visit_with(&mut visitor::skipped_component_reporter());
+        }
+
+        // Signature functions of nested components should not conflict with the module's names.
+        if self.options.nested_components {
+            let mut collector = visitor::ident_name_collector();
+            module_items.visit_with(&mut collector);
+            self.used_names = collector.get_names();
+        }
+
+        // Dispose handlers are registered even if the module has no components,
+        // so check the registrations to avoid registering them twice.
+        let mut finder = RuntimeCallFinder {
+            fn_name: RUNTIME_ADD_DISPOSE_HANDLER_FN,
+            is_found: false,
+        };
+        module_items.visit_with(&mut finder);
+        self.has_dispose_registration = finder.is_found;
+
+        // `// @refresh reset` at the top of the module forces remount all components.
+        self.force_reset = module_items
+            .first()
+            .is_some_and(|module_item| self.has_comment(module_item, REFRESH_RESET_DIRECTIVE));
+    }
+
+    /// Returns `true` if the leading comments of the item contains the directive.
+    fn has_comment(&self, module_item: &ModuleItem, directive: &str) -> bool {
+        has_leading_comment(&self.comments, module_item.span_lo(), directive)
+    }
+
+    /// Returns `true` if the module has already been transformed by this plugin.
+    ///
+    /// Code: `var __prevRefreshReg = global.$RefreshReg$;`
+    fn is_instrumented(&self, module_items: &[ModuleItem]) -> bool {
+        module_items.iter().any(|module_item| {
+            module_item
+                .as_stmt()
+                .and_then(|stmt| stmt.as_decl())
+                .and_then(|decl| decl.as_var())
+                .and_then(|var_decl| var_decl.decls.first())
+                .and_then(|var_decl| var_decl.name.as_ident())
+                .is_some_and(|binding| &*binding.id.sym == TEMP_REGISTER_REF)
+        })
+    }
+
+    /// Returns `true` if react-refresh is disabled for the module.
+    ///
+    /// Code: `"use no refresh";`
+    /// Code: `/* @refresh-disable */`
+    fn is_disabled(&self, module_items: &[ModuleItem]) -> bool {
+        has_directive(module_items, USE_NO_REFRESH_DIRECTIVE)
+            || module_items
+                .first()
+                .is_some_and(|module_item| self.has_comment(module_item, REFRESH_DISABLE_DIRECTIVE))
+    }
+
+    /// Returns `true` if the module is a server module.
+    ///
+    /// Code: `"use server";`
+    ///
+    /// When `rsc` option is enabled, modules without `"use client"` directive
+    /// are Server Components module.
+    fn is_server_module(&self, module_items: &[ModuleItem]) -> bool {
+        has_directive(module_items, USE_SERVER_DIRECTIVE)
+            || (self.options.rsc && !has_directive(module_items, USE_CLIENT_DIRECTIVE))
+    }
+
+    /// Returns `true` if the module should not be transformed.
+    ///
+    /// Modules that are already instrumented are skipped
+    /// to avoid duplicated registrations and signatures.
+    fn is_skipped(&self, module_items: &[ModuleItem]) -> bool {
+        self.is_instrumented(module_items)
+            || self.is_disabled(module_items)
+            || self.is_server_module(module_items)
     }
 
     /// Returns id
-    fn get_id(&self, identifier: &String) -> String {
+    fn get_id(&self, identifier: &str) -> String {
         let mut owned_string = self.module_id.to_owned();
-        owned_string.push_str(":");
-        owned_string.push_str(identifier.as_str());
+        owned_string.push(':');
+        owned_string.push_str(identifier);
         owned_string
     }
 
-    /// Fold with ReactRefreshRuntimeComponent if it is valid React component.
+    /// Visit with ReactRefreshRuntimeComponent if it is valid React component.
     ///
-    /// Returns `true` when folded and otherwise returns `false`
-    fn fold_if_react_component(&mut self, module: &ModuleItem, ident: &Ident) -> bool {
+    /// Returns `true` when visited and otherwise returns `false`
+    fn visit_mut_if_react_component<N>(
+        &mut self,
+        node: &mut N,
+        ident: &Ident,
+        force_reset: bool,
+    ) -> bool
+    where
+        N: for<'b> VisitMutWith<ReactRefreshRuntimeComponent<'b>>
+            + for<'b> VisitWith<HookCallLinter<'b>>
+            + Spanned,
+    {
         let component_name = get_name_from_ident(ident);
+        let reference = ident_str_expr(&component_name, ident.span);
+        self.visit_mut_if_react_component_with_ref(node, component_name, reference, force_reset)
+    }
+
+    /// Visit with ReactRefreshRuntimeComponent if it is valid React component
+    /// that is referenced by the given expression.
+    ///
+    /// Returns `true` when visited and otherwise returns `false`
+    fn visit_mut_if_react_component_with_ref<N>(
+        &mut self,
+        node: &mut N,
+        component_name: String,
+        reference: Expr,
+        force_reset: bool,
+    ) -> bool
+    where
+        N: for<'b> VisitMutWith<ReactRefreshRuntimeComponent<'b>>
+            + for<'b> VisitWith<HookCallLinter<'b>>
+            + Spanned,
+    {
         if is_componentish_name(&component_name)
             && !self.component_names.contains(&component_name)
             && !self.black_list.contains(&component_name)
         {
-            let component = &mut ReactRefreshRuntimeComponent::default();
-            let component_stmt = module.to_owned().fold_children_with(component);
+            let component = &mut ReactRefreshRuntimeComponent::default(&self.hook_matcher);
+            node.visit_mut_children_with(component);
 
             if !component.is_empty {
-                self.module_body.push(component_stmt);
+                let span = node.span();
+                if self.options.lint {
+                    node.visit_with(&mut HookCallLinter::default(&self.hook_matcher));
+                }
+
                 self.component_names.insert(component_name.to_owned());
                 self.component_list.push(ComponentMeta {
-                    span: ident.span,
-                    name: component_name.to_owned(),
+                    name: component_name,
+                    reference,
+                    span,
                     builtin_hook_count: component.builtin_hook_count,
                     custom_hook_count: component.custom_hook_count,
+                    hooks: std::mem::take(&mut component.hooks),
+                    custom_hooks: std::mem::take(&mut component.custom_hooks),
+                    force_reset,
+                    is_lazy: false,
+                    is_reassigned: false,
                 });
                 return true;
             }
@@ -213,20 +1039,244 @@ impl ReactRefreshRuntime {
         false
     }
 
-    /// Fold with ReactRefreshRuntimeComponent if it is valid React component.
+    /// Visit with ReactRefreshRuntimeComponent if it is valid React component.
+    ///
+    /// Returns `true` when visited and otherwise returns `false`
+    fn transform_var_declarator(
+        &mut self,
+        var_decl: &mut VarDeclarator,
+        force_reset: bool,
+    ) -> bool {
+        let (Some(binding), Some(init_expr)) = (var_decl.name.as_ident(), &var_decl.init) else {
+            return false;
+        };
+        let ident = binding.id.to_owned();
+
+        // Unwrap TypeScript expressions and parentheses.
+        //
+        // - `const Component = (() => {}) as FC;`
+        // - `const Component = <FC>(() => {});`
+        let init_expr = unwrap_expr(init_expr);
+        match init_expr {
+            // Contexts are not components.
+            Expr::Call(_) if is_create_context_call(init_expr) => false,
+            // Preserved values are not components. (eg. transformed twice)
+            Expr::Call(call_expr)
+                if is_runtime_call(call_expr, RUNTIME_GET_OR_CREATE_CONTEXT_FN)
+                    || is_runtime_call(call_expr, RUNTIME_GET_PERSISTED_VALUE_FN) =>
+            {
+                false
+            }
+            Expr::Call(_) if is_lazy_call(init_expr) => {
+                self.register_lazy_component(&ident, var_decl.span)
+            }
+            Expr::Fn(_) | Expr::Arrow(_) | Expr::Call(_) => {
+                self.visit_mut_if_react_component(var_decl, &ident, force_reset)
+            }
+            _ => false,
+        }
+    }
+
+    /// Register the lazy component without signature.
+    ///
+    /// Code: `const Component = lazy(() => import('...'));`
     ///
-    /// Returns `true` when folded and otherwise returns `false`
-    fn fold_var_declarator(&mut self, module: &ModuleItem, var_decl: &VarDeclarator) -> bool {
-        if let (Some(ident), Some(init_expr)) = (var_decl.name.as_ident(), var_decl.init.to_owned())
+    /// Returns `true` when registered and otherwise returns `false`
+    fn register_lazy_component(&mut self, ident: &Ident, span: Span) -> bool {
+        let component_name = get_name_from_ident(ident);
+
+        if is_componentish_name(&component_name)
+            && !self.component_names.contains(&component_name)
+            && !self.black_list.contains(&component_name)
         {
-            match *init_expr {
-                Expr::Fn(_) | Expr::Arrow(_) | Expr::Call(_) => {
-                    return self.fold_if_react_component(module, ident);
+            self.component_names.insert(component_name.to_owned());
+            self.component_list.push(ComponentMeta {
+                reference: ident_str_expr(&component_name, ident.span),
+                name: component_name,
+                span,
+                builtin_hook_count: 0,
+                custom_hook_count: 0,
+                hooks: Vec::new(),
+                custom_hooks: Vec::new(),
+                force_reset: false,
+                is_lazy: true,
+                is_reassigned: false,
+            });
+            return true;
+        }
+        false
+    }
+
+    /// Sign components that are declared inside the top-level function.
+    fn visit_mut_nested_components(&mut self, module: &mut ModuleItem) {
+        let parent_name = get_declared_idents(module)
+            .first()
+            .map_or(String::from("default"), |ident| get_name_from_ident(ident));
+        let force_reset = self.force_reset || self.has_comment(module, REFRESH_RESET_DIRECTIVE);
+        let signer = &mut NestedComponentSigner::default(
+            &self.hook_matcher,
+            self.get_id(&parent_name),
+            force_reset,
+            &self.used_names,
+            std::mem::take(&mut self.nested_signature_refs),
+        );
+        module.visit_mut_with(signer);
+        self.nested_signature_refs = std::mem::take(&mut signer.signature_refs);
+    }
+
+    /// Register the intermediate value of the component before reassigned through HOCs.
+    ///
+    /// Code: `Component = memo(Component);`
+    ///
+    /// ```js
+    /// __s(Component, "module_id:Component", false); // Only before the first reassignment.
+    /// global.$RefreshReg$(Component, "Component$memo");
+    /// Component = memo(Component);
+    /// ```
+    fn register_reassigned_component(&mut self, assign: &AssignExpr) {
+        if assign.op != AssignOp::Assign {
+            return;
+        }
+
+        let (Some(ident), Expr::Call(call)) = (assign.left.as_ident(), unwrap_expr(&assign.right))
+        else {
+            return;
+        };
+
+        let component_name = get_name_from_ident(ident);
+        let Some(component_index) = self
+            .component_list
+            .iter()
+            .position(|component| component.name == component_name && !component.is_lazy)
+        else {
+            return;
+        };
+
+        let reference = ident_str_expr(&component_name, ident.span);
+        let component = &self.component_list[component_index];
+        if !component.is_reassigned
+            && component.builtin_hook_count + component.custom_hook_count > 0
+        {
+            let signature_stmt = self.get_call_signature_fn_stmt(
+                &component_name,
+                &reference,
+                component.custom_hook_count > 0 || component.force_reset,
+                component.span,
+            );
+            self.module_body.push(ModuleItem::Stmt(signature_stmt));
+        }
+        self.component_list[component_index].is_reassigned = true;
+
+        let hoc_name = get_callee_name(call).unwrap_or(String::from("hoc"));
+        self.module_body
+            .push(ModuleItem::Stmt(self.get_call_register_fn_stmt(
+                &format!("{}${}", component_name, hoc_name),
+                &reference,
+                assign.span,
+            )));
+    }
+
+    /// Wrap top-level variable initializers of the module item
+    /// with the runtime function to reuse the value of previous module.
+    ///
+    /// Code: `const value = global.$RefreshRuntime$.runtimeFn("module_id:value", () => init);`
+    fn wrap_initializers(
+        &self,
+        module: &mut ModuleItem,
+        runtime_fn: &str,
+        should_wrap: impl Fn(&VarDeclKind, &Expr) -> bool,
+    ) {
+        let var_decl = match module {
+            ModuleItem::Stmt(Stmt::Decl(Decl::Var(var_decl))) => var_decl,
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+                decl: Decl::Var(var_decl),
+                ..
+            })) => var_decl,
+            _ => return,
+        };
+
+        for var_declarator in var_decl.decls.iter_mut() {
+            if let (Some(binding), Some(init_expr)) =
+                (var_declarator.name.as_ident(), var_declarator.init.as_mut())
+            {
+                // Already wrapped. (eg. transformed twice)
+                let is_wrapped = init_expr
+                    .as_call()
+                    .is_some_and(|call_expr| is_runtime_call(call_expr, runtime_fn));
+
+                if !is_wrapped && should_wrap(&var_decl.kind, init_expr) {
+                    let id = format!("{}:{}", self.module_id, binding.id.sym);
+                    **init_expr =
+                        self.get_call_runtime_initializer_expr(runtime_fn, &id, *init_expr.take());
                 }
-                _ => (),
             }
         }
-        false
+    }
+
+    /// Preserve values of previous module if possible.
+    ///
+    /// - `// @refresh persist` on top-level `let` and `const` declarations.
+    /// - `const Context = createContext(null);` (If `preserveContext` option is enabled)
+    fn preserve_values(&self, module: &mut ModuleItem) {
+        if self.has_comment(module, REFRESH_PERSIST_DIRECTIVE) {
+            self.wrap_initializers(module, RUNTIME_GET_PERSISTED_VALUE_FN, |kind, _| {
+                *kind != VarDeclKind::Var
+            });
+        } else if self.options.preserve_context {
+            self.wrap_initializers(module, RUNTIME_GET_OR_CREATE_CONTEXT_FN, |_, init_expr| {
+                is_create_context_call(init_expr)
+            });
+        }
+    }
+
+    /// Visit with ReactRefreshRuntimeComponent if it is valid React component
+    /// that is defined by CommonJS export.
+    ///
+    /// Returns `true` when visited and otherwise returns `false`
+    fn visit_mut_commonjs_export(&mut self, assign: &mut AssignExpr, force_reset: bool) -> bool {
+        if assign.op != AssignOp::Assign {
+            return false;
+        }
+
+        let export_target = match assign.left.as_expr() {
+            Some(Expr::Member(member_expr)) => member_expr,
+            _ => return false,
+        };
+
+        let component = match &*assign.right {
+            // `module.exports = function MyComponent() {};`
+            Expr::Fn(FnExpr {
+                ident: Some(fn_ident),
+                ..
+            }) if is_module_exports(export_target) => Some(get_name_from_ident(fn_ident)),
+            // `exports.MyComponent = () => {};`
+            // `module.exports.MyComponent = () => {};`
+            Expr::Fn(_) | Expr::Arrow(_) | Expr::Call(_) => {
+                match (export_target.obj.as_ref(), export_target.prop.as_ident()) {
+                    (obj, Some(prop_ident))
+                        if is_exports_ident(obj)
+                            || obj.as_member().is_some_and(is_module_exports) =>
+                    {
+                        Some(get_name_from_ident(prop_ident))
+                    }
+                    _ => None,
+                }
+            }
+            _ => None,
+        };
+
+        match component {
+            Some(component_name) => {
+                let reference = Expr::Member(export_target.to_owned());
+                self.visit_mut_if_react_component_with_ref(
+                    assign,
+                    component_name,
+                    reference,
+                    force_reset,
+                )
+            }
+            None => false,
+        }
     }
 
     /// Returns a statement that temporarily stores the registration function.
@@ -274,9 +1324,9 @@ impl ReactRefreshRuntime {
     /// and assigns it after create the signature function.
     ///
     /// Code: `var __s = global.$RefreshSig$();`
-    fn get_create_signature_fn_stmt(&self) -> Stmt {
+    fn get_create_signature_fn_stmt(&self, signature_ref: &str) -> Stmt {
         decl_var_and_assign_stmt(
-            ident(js_word!(SIGNATURE_FN)),
+            ident(signature_ref.into()),
             call_expr(
                 obj_prop_expr(ident_expr(js_word!(GLOBAL)), ident(js_word!(SIGNATURE_REF))),
                 vec![],
@@ -286,51 +1336,174 @@ impl ReactRefreshRuntime {
 
     /// Returns a statement that call the created signature function.
     ///
-    /// Code: `__s(Component, "module_id", has_custom_hook_call);`
+    /// Code: `__s(Component, "module_id", force_reset);`
     fn get_call_signature_fn_stmt(
         &self,
-        component_name: &String,
+        component_name: &str,
+        reference: &Expr,
+        force_reset: bool,
         span: Span,
-        has_custom_hook_call: bool,
     ) -> Stmt {
-        to_stmt(call_expr(
-            ident_expr(js_word!(SIGNATURE_FN)),
-            vec![
-                arg_expr(ident_str_expr(component_name, span)),
-                arg_expr(str_expr(&self.get_id(component_name))),
-                arg_expr(bool_expr(has_custom_hook_call)),
-            ],
-        ))
+        to_stmt_with_span(
+            get_signature_call_expr(
+                SIGNATURE_FN,
+                reference.to_owned(),
+                &self.get_id(component_name),
+                force_reset,
+                span,
+            ),
+            span,
+        )
     }
 
     /// Returns a statement that call the register function.
     ///
     /// Code: `global.$RefreshRef$(Component, "Component");`
-    fn get_call_register_fn_stmt(&self, component_name: &String, span: Span) -> Stmt {
-        to_stmt(call_expr(
-            obj_prop_expr(ident_expr(js_word!(GLOBAL)), ident(js_word!(REGISTER_REF))),
-            vec![
-                arg_expr(ident_str_expr(component_name, span)),
-                arg_expr(str_expr(component_name)),
-            ],
-        ))
+    fn get_call_register_fn_stmt(
+        &self,
+        component_name: &str,
+        reference: &Expr,
+        span: Span,
+    ) -> Stmt {
+        to_stmt_with_span(
+            call_expr_with_span(
+                obj_prop_expr(ident_expr(js_word!(GLOBAL)), ident(js_word!(REGISTER_REF))),
+                vec![
+                    arg_expr(reference.to_owned()),
+                    arg_expr(str_expr(component_name)),
+                ],
+                span,
+            ),
+            span,
+        )
     }
 
     /// Returns a statement that call the HMR accept method.
     ///
     /// Code: `global.$RefreshRuntime$.getContext().accept(Component);`
-    fn get_call_accept_stmt(&self, component_name: &String, span: Span) -> Stmt {
+    fn get_call_accept_stmt(&self, reference: &Expr, span: Span) -> Stmt {
         let call_get_ctx_fn = call_expr(
             obj_prop_expr(
                 obj_prop_expr(ident_expr(js_word!(GLOBAL)), ident(js_word!(RUNTIME_REF))),
                 ident(js_word!(RUNTIME_GET_CONTEXT_FN)),
             ),
-            vec![arg_expr(ident_str_expr(component_name, span))],
+            vec![arg_expr(reference.to_owned())],
         );
 
+        to_stmt_with_span(
+            call_expr_with_span(
+                obj_prop_expr(call_get_ctx_fn, ident(js_word!(CONTEXT_ACCEPT_FN))),
+                vec![],
+                span,
+            ),
+            span,
+        )
+    }
+
+    /// Returns exports that are not components.
+    ///
+    /// The dispose handler is not a part of the module interface.
+    fn get_non_component_exports(&self) -> impl Iterator<Item = &ExportMeta> {
+        self.exports.iter().filter(|export| {
+            export.name != DISPOSE_HANDLER
+                && export
+                    .local
+                    .as_ref()
+                    .is_none_or(|local| !self.component_names.contains(local))
+        })
+    }
+
+    /// Returns `true` if the module is a boundary.
+    ///
+    /// The module is a boundary only when all of exports are components.
+    fn is_boundary(&self) -> bool {
+        !self.exports.is_empty() && self.get_non_component_exports().next().is_none()
+    }
+
+    /// Report exports that break the refresh boundary of the module.
+    ///
+    /// Code: `export const helper = () => {};` (with components)
+    fn report_non_component_exports(&self) {
+        for export in self.get_non_component_exports() {
+            emit_warning(
+                export.span,
+                &format!(
+                    "Export \"{}\" is not a component. Modules that export both components and non-components can't be refreshed and cause a full reload.",
+                    export.name
+                ),
+            );
+        }
+    }
+
+    /// Returns a statement that registers the module metadata for HMR boundary.
+    ///
+    /// Code: `global.$RefreshRuntime$.registerModule?.("module_id", { exports: [...], components: [...], isBoundary });`
+    ///
+    /// Guarded with optional call because runtimes may not implement it.
+    fn get_call_register_module_stmt(&self) -> Stmt {
+        let is_boundary = self.is_boundary();
+
+        to_stmt(opt_call_expr(
+            obj_prop_expr(
+                obj_prop_expr(ident_expr(js_word!(GLOBAL)), ident(js_word!(RUNTIME_REF))),
+                ident(js_word!(RUNTIME_REGISTER_MODULE_FN)),
+            ),
+            vec![
+                arg_expr(str_expr(&self.module_id)),
+                arg_expr(object_expr(vec![
+                    (
+                        "exports",
+                        array_expr(
+                            self.exports
+                                .iter()
+                                .map(|export| str_expr(&export.name))
+                                .collect(),
+                        ),
+                    ),
+                    (
+                        "components",
+                        array_expr(
+                            self.component_list
+                                .iter()
+                                .map(|component| str_expr(&component.name))
+                                .collect(),
+                        ),
+                    ),
+                    ("isBoundary", bool_expr(is_boundary)),
+                ])),
+            ],
+        ))
+    }
+
+    /// Returns an expression that gets the value of previous module or creates new one.
+    ///
+    /// Code: `global.$RefreshRuntime$.runtimeFn("module_id:value", () => init)`
+    fn get_call_runtime_initializer_expr(
+        &self,
+        runtime_fn: &str,
+        id: &str,
+        init_expr: Expr,
+    ) -> Expr {
+        call_expr(
+            obj_prop_expr(
+                obj_prop_expr(ident_expr(js_word!(GLOBAL)), ident(js_word!(RUNTIME_REF))),
+                ident(runtime_fn.into()),
+            ),
+            vec![arg_expr(str_expr(id)), arg_expr(arrow_expr(init_expr))],
+        )
+    }
+
+    /// Returns a statement that call the module scoped runtime function.
+    ///
+    /// Code: `global.$RefreshRuntime$.beginModule("module_id");`
+    /// Code: `global.$RefreshRuntime$.endModule("module_id");`
+    fn get_call_runtime_module_fn_stmt(&self, fn_name: Atom) -> Stmt {
         to_stmt(call_expr(
-            obj_prop_expr(call_get_ctx_fn, ident(js_word!(CONTEXT_ACCEPT_FN))),
-            vec![],
+            obj_prop_expr(
+                obj_prop_expr(ident_expr(js_word!(GLOBAL)), ident(js_word!(RUNTIME_REF))),
+                ident(fn_name),
+            ),
+            vec![arg_expr(str_expr(&self.module_id))],
         ))
     }
 
@@ -347,17 +1520,21 @@ impl ReactRefreshRuntime {
 
     /// Setup react-refresh
     fn setup_react_refresh_global(&mut self) {
-        let has_defined_component = self.component_names.len() > 0;
+        let has_defined_component = !self.component_names.is_empty();
+        let has_nested_signature = !self.nested_signature_refs.is_empty();
         let mut is_sig_required = false;
 
-        if !has_defined_component {
+        if !has_defined_component && !has_nested_signature {
             return;
         }
 
         // var __prevRefreshReg = global.$RefreshReg$;
         // var __prevRefreshSig = global.$RefreshSig$;
+        // Directives (eg. `"use client"`) should be placed at the top of the module.
+        let offset = directive_count(&self.module_body);
+
         self.module_body.insert(
-            0,
+            offset,
             ModuleItem::Stmt(
                 self.get_assign_temp_ref_fn_stmt(
                     js_word!(TEMP_REGISTER_REF),
@@ -366,19 +1543,30 @@ impl ReactRefreshRuntime {
             ),
         );
         self.module_body.insert(
-            1,
+            offset + 1,
             ModuleItem::Stmt(self.get_assign_temp_ref_fn_stmt(
                 js_word!(TEMP_SIGNATURE_REF),
                 js_word!(SIGNATURE_REF),
             )),
         );
-        self.module_body
-            .insert(2, ModuleItem::Stmt(self.get_assign_register_fn_stmt()));
+        self.module_body.insert(
+            offset + 2,
+            ModuleItem::Stmt(self.get_assign_register_fn_stmt()),
+        );
+
+        // Begin batching registrations of the module.
+        //
+        // global.$RefreshRuntime$.beginModule("module_id");
+        if self.options.batch_refresh {
+            self.module_body.push(ModuleItem::Stmt(
+                self.get_call_runtime_module_fn_stmt(js_word!(RUNTIME_BEGIN_MODULE_FN)),
+            ));
+        }
 
         // Append the code below at the bottom.
         // - call signature
         // - registration
-        // - accept (= performReactRefresh)
+        // - accept (= performReactRefresh, skip when batching registrations)
         //
         // __s(Component, "module_id"); // Add when component has hooks.
         // global.$RefreshReg$(Component, "Component");
@@ -386,31 +1574,70 @@ impl ReactRefreshRuntime {
         for component in self.component_list.iter() {
             let has_hook = component.builtin_hook_count + component.custom_hook_count > 0;
             is_sig_required = is_sig_required || has_hook;
-            if has_hook {
+            if has_hook && !component.is_reassigned {
                 self.module_body
                     .push(ModuleItem::Stmt(self.get_call_signature_fn_stmt(
                         &component.name,
+                        &component.reference,
+                        // Custom hooks can't be tracked, so always remount it.
+                        component.custom_hook_count > 0 || component.force_reset,
                         component.span,
-                        component.custom_hook_count > 0,
                     )));
             }
-            self.module_body.push(ModuleItem::Stmt(
-                self.get_call_register_fn_stmt(&component.name, component.span),
-            ));
-            self.module_body.push(ModuleItem::Stmt(
-                self.get_call_accept_stmt(&component.name, component.span),
-            ));
+            self.module_body
+                .push(ModuleItem::Stmt(self.get_call_register_fn_stmt(
+                    &component.name,
+                    &component.reference,
+                    component.span,
+                )));
+            // Lazy components are accepted too, so modules that only have lazy components are refreshed.
+            if !self.options.batch_refresh {
+                self.module_body.push(ModuleItem::Stmt(
+                    self.get_call_accept_stmt(&component.reference, component.span),
+                ));
+            }
         }
 
         // Define a signature function if some components use hooks.
+        // (And each signature function of the nested components)
         //
         // global.$RefreshSig$ = global.$RefreshRuntime$.createSignatureFunctionForTransform;
         // var __s = global.$RefreshSig$();
-        if is_sig_required {
+        // var _s = global.$RefreshSig$();
+        if is_sig_required || has_nested_signature {
+            let signature_refs = is_sig_required
+                .then(|| String::from(SIGNATURE_FN))
+                .into_iter()
+                .chain(self.nested_signature_refs.iter().cloned());
+            let signature_stmts = std::iter::once(self.get_assign_signature_fn_stmt())
+                .chain(
+                    signature_refs
+                        .map(|signature_ref| self.get_create_signature_fn_stmt(&signature_ref)),
+                )
+                .map(ModuleItem::Stmt)
+                .collect::<Vec<ModuleItem>>();
             self.module_body
-                .insert(3, ModuleItem::Stmt(self.get_assign_signature_fn_stmt()));
-            self.module_body
-                .insert(4, ModuleItem::Stmt(self.get_create_signature_fn_stmt()));
+                .splice(offset + 3..offset + 3, signature_stmts);
+        }
+
+        // Exports that are not components break the refresh boundary.
+        if self.options.diagnostics && !self.component_names.is_empty() {
+            self.report_non_component_exports();
+        }
+
+        // Register the module metadata to decide HMR boundary.
+        //
+        // global.$RefreshRuntime$.registerModule?.("module_id", { exports, components, isBoundary });
+        self.module_body
+            .push(ModuleItem::Stmt(self.get_call_register_module_stmt()));
+
+        // End batching registrations and perform refresh once for the module.
+        //
+        // global.$RefreshRuntime$.endModule("module_id");
+        if self.options.batch_refresh {
+            self.module_body.push(ModuleItem::Stmt(
+                self.get_call_runtime_module_fn_stmt(js_word!(RUNTIME_END_MODULE_FN)),
+            ));
         }
 
         // Finally, restore the original react-refresh functions.
@@ -426,18 +1653,27 @@ impl ReactRefreshRuntime {
                 js_word!(TEMP_SIGNATURE_REF),
             )));
     }
-}
 
-impl Fold for ReactRefreshRuntime {
-    noop_fold_type!();
+    /// Find React components from module items.
+    /// And then add signature, register components and accept for HMR.
+    fn transform_module_items(&mut self, module_items: &mut Vec<ModuleItem>) {
+        self.initialize_before_visit_module();
+        self.prepare_before_visit_module(module_items);
 
-    fn fold_module(&mut self, module: Module) -> Module {
-        self.initialize_before_fold_module();
-        self.prepare_before_fold_module(&module);
-        let mut is_folded: bool;
+        for mut module in std::mem::take(module_items) {
+            // 0. Sign components that are declared inside top-level functions if enabled.
+            //    - `function createScreen() { return function Screen() {}; }`
+            if self.options.nested_components {
+                self.visit_mut_nested_components(&mut module);
+            }
 
-        for module in module.body.iter() {
-            is_folded = false;
+            let force_reset =
+                self.force_reset || self.has_comment(&module, REFRESH_RESET_DIRECTIVE);
+            let is_dispose_handler = !self.has_dispose_registration
+                && get_declared_idents(&module)
+                    .iter()
+                    .any(|ident| &*ident.sym == DISPOSE_HANDLER);
+            let mut is_visited = false;
 
             // 1. Find variable declare statements and check it is React component.
             //    - `const MyComponent = () => {};`
@@ -451,56 +1687,194 @@ impl Fold for ReactRefreshRuntime {
             //    - `export function MyComponent() {};`
             //    - `export { NamedA, NamedB, NamedC };`
             //    - `export default function MyComponent() {};`
-            if let ModuleItem::Stmt(Stmt::Decl(Decl::Var(var_decl))) = module {
-                let decl: Option<&VarDeclarator> = var_decl.decls.get(0);
-                let is_single_decl = var_decl.decls.len() == 1;
-                if let Some(var_decl) = decl {
-                    if is_single_decl {
-                        is_folded = self.fold_var_declarator(module, var_decl);
+            //
+            // 4. Find CommonJS exports that define React component.
+            //    - `module.exports = function MyComponent() {};`
+            //    - `exports.MyComponent = () => {};`
+            //    - `module.exports.MyComponent = () => {};`
+            //
+            //    Components reassigned through HOCs are also registered.
+            //    - `MyComponent = memo(MyComponent);`
+            match &mut module {
+                ModuleItem::Stmt(Stmt::Decl(Decl::Var(var_decl))) => {
+                    if let [var_declarator] = var_decl.decls.as_mut_slice() {
+                        is_visited = self.transform_var_declarator(var_declarator, force_reset);
                     }
                 }
-            } else if let ModuleItem::Stmt(Stmt::Decl(Decl::Fn(fn_decl))) = module {
-                is_folded = self.fold_if_react_component(module, &fn_decl.ident);
-            } else if let ModuleItem::ModuleDecl(module_decl) = module {
-                if let Some(named_export) = module_decl.as_export_decl() {
-                    match &named_export.decl {
-                        Decl::Var(named_var_export) => {
-                            for var_decl in named_var_export.decls.iter() {
-                                is_folded = self.fold_var_declarator(module, var_decl);
+                ModuleItem::Stmt(Stmt::Decl(Decl::Fn(fn_decl))) => {
+                    let ident = fn_decl.ident.to_owned();
+                    is_visited = self.visit_mut_if_react_component(fn_decl, &ident, force_reset);
+                }
+                ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(export_decl)) => {
+                    match &mut export_decl.decl {
+                        Decl::Var(var_decl) => {
+                            for var_declarator in var_decl.decls.iter_mut() {
+                                is_visited |=
+                                    self.transform_var_declarator(var_declarator, force_reset);
                             }
                         }
-                        Decl::Fn(named_fn_export) => {
-                            is_folded =
-                                self.fold_if_react_component(module, &named_fn_export.ident);
+                        Decl::Fn(fn_decl) => {
+                            let ident = fn_decl.ident.to_owned();
+                            is_visited =
+                                self.visit_mut_if_react_component(fn_decl, &ident, force_reset);
                         }
                         _ => (),
                     }
-                } else if let Some(fn_ident) = module_decl
-                    .as_export_default_decl()
-                    .and_then(|default_decl| default_decl.decl.as_fn_expr())
-                    .and_then(|fn_expr| fn_expr.ident.to_owned())
-                {
-                    is_folded = self.fold_if_react_component(module, &fn_ident);
                 }
+                ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultDecl(ExportDefaultDecl {
+                    decl: DefaultDecl::Fn(fn_expr),
+                    ..
+                })) => {
+                    if let Some(ident) = fn_expr.ident.to_owned() {
+                        is_visited =
+                            self.visit_mut_if_react_component(fn_expr, &ident, force_reset);
+                    }
+                }
+                ModuleItem::Stmt(Stmt::Expr(ExprStmt { expr, .. })) => {
+                    if let Expr::Assign(assign) = &mut **expr {
+                        self.register_reassigned_component(assign);
+                        is_visited = self.visit_mut_commonjs_export(assign, force_reset);
+                    }
+                }
+                _ => (),
+            }
+
+            // 5. Register handlers of HMR dispose API through the runtime. (The original calls are kept)
+            //    - `import.meta.hot.dispose(() => {});`
+            //    - `import.meta.hot?.dispose(() => {});`
+            //    - `if (import.meta.hot) { import.meta.hot.dispose(() => {}); }`
+            if !self.has_dispose_registration {
+                module.visit_mut_with(&mut DisposeCallRegistrar {
+                    module_id: &self.module_id,
+                });
+            }
+
+            // 6. If React component not found, use original statement.
+            //    Values of previous module are reused if possible.
+            //    - `const Context = createContext(null);`
+            //    - `// @refresh persist`
+            if !is_visited {
+                self.preserve_values(&mut module);
             }
+            self.module_body.push(module);
 
-            // 4. If React component not found, use original statement.
-            if !is_folded {
-                self.module_body.push(module.to_owned());
+            // 7. Register the dispose handler of the module.
+            //    - `function __refreshDispose() {}`
+            //    - `export const __refreshDispose = () => {};`
+            if is_dispose_handler {
+                self.module_body
+                    .push(ModuleItem::Stmt(to_stmt(get_add_dispose_handler_expr(
+                        &self.module_id,
+                        ident_expr(js_word!(DISPOSE_HANDLER)),
+                    ))));
             }
         }
 
         self.setup_react_refresh_global();
 
-        Module {
-            body: self.module_body.to_owned(),
-            ..module
+        *module_items = std::mem::take(&mut self.module_body);
+    }
+
+    /// Returns the report of components and exports that are found.
+    fn get_report(&self, is_skipped: bool) -> RefreshReport {
+        let mut custom_hooks: Vec<String> = self
+            .component_list
+            .iter()
+            .flat_map(|component| component.custom_hooks.iter().cloned())
+            .collect();
+        custom_hooks.sort();
+        custom_hooks.dedup();
+
+        let mut black_list: Vec<String> = self.black_list.iter().cloned().collect();
+        black_list.sort();
+
+        RefreshReport {
+            is_skipped,
+            components: self
+                .component_list
+                .iter()
+                .map(|component| ComponentReport {
+                    name: component.name.to_owned(),
+                    hooks: component.hooks.to_owned(),
+                    custom_hooks: component.custom_hooks.to_owned(),
+                    // Only when the signature is emitted. (Components without hooks aren't signed)
+                    force_reset: component.builtin_hook_count + component.custom_hook_count > 0
+                        && (component.custom_hook_count > 0 || component.force_reset),
+                    is_lazy: component.is_lazy,
+                })
+                .collect(),
+            custom_hooks,
+            black_list,
+            exports: self
+                .exports
+                .iter()
+                .map(|export| export.name.to_owned())
+                .collect(),
+            is_boundary: !is_skipped && self.is_boundary(),
         }
     }
 }
 
-pub fn react_refresh(module_id: String) -> ReactRefreshRuntime {
-    ReactRefreshRuntime::default(module_id)
+impl<C: Comments> VisitMut for ReactRefreshRuntime<C> {
+    noop_visit_mut_type!();
+
+    fn visit_mut_module(&mut self, module: &mut Module) {
+        self.visit_mut_module_items(&mut module.body);
+    }
+
+    fn visit_mut_module_items(&mut self, module_items: &mut Vec<ModuleItem>) {
+        if self.is_skipped(module_items) {
+            return;
+        }
+
+        self.transform_module_items(module_items);
+    }
+
+    fn visit_mut_script(&mut self, script: &mut Script) {
+        let mut module_items: Vec<ModuleItem> = std::mem::take(&mut script.body)
+            .into_iter()
+            .map(ModuleItem::Stmt)
+            .collect();
+
+        self.visit_mut_module_items(&mut module_items);
+
+        script.body = module_items
+            .into_iter()
+            .filter_map(|module_item| module_item.stmt())
+            .collect();
+    }
+}
+
+pub fn react_refresh<C: Comments>(
+    module_id: String,
+    options: TransformOptions,
+    comments: Option<C>,
+) -> impl Fold + VisitMut {
+    as_folder(ReactRefreshRuntime::default(module_id, options, comments))
+}
+
+/// Find React components from the module without transforming.
+pub fn analyze<C: Comments>(
+    module: &Module,
+    options: TransformOptions,
+    comments: Option<C>,
+) -> RefreshReport {
+    // Warnings are not reported because the analysis may run without the handler.
+    let options = TransformOptions {
+        lint: false,
+        diagnostics: false,
+        ..options
+    };
+    let mut runtime = ReactRefreshRuntime::default(String::new(), options, comments);
+    let is_skipped = runtime.is_skipped(&module.body);
+
+    if is_skipped {
+        runtime.prepare_before_visit_module(&module.body);
+    } else {
+        runtime.transform_module_items(&mut module.body.to_owned());
+    }
+
+    runtime.get_report(is_skipped)
 }
 
 #[cfg(test)]
@@ -530,3 +1904,55 @@ mod hooks;
 #[cfg(test)]
 #[path = "./tests/import.rs"]
 mod import;
+
+#[cfg(test)]
+#[path = "./tests/commonjs.rs"]
+mod commonjs;
+
+#[cfg(test)]
+#[path = "./tests/directive.rs"]
+mod directive;
+
+#[cfg(test)]
+#[path = "./tests/rsc.rs"]
+mod rsc;
+
+#[cfg(test)]
+#[path = "./tests/batch.rs"]
+mod batch;
+
+#[cfg(test)]
+#[path = "./tests/dispose.rs"]
+mod dispose;
+
+#[cfg(test)]
+#[path = "./tests/context.rs"]
+mod context;
+
+#[cfg(test)]
+#[path = "./tests/typescript.rs"]
+mod typescript;
+
+#[cfg(test)]
+#[path = "./tests/nested.rs"]
+mod nested;
+
+#[cfg(test)]
+#[path = "./tests/warnings.rs"]
+mod warnings;
+
+#[cfg(test)]
+#[path = "./tests/lint.rs"]
+mod lint;
+
+#[cfg(test)]
+#[path = "./tests/diagnostics.rs"]
+mod diagnostics;
+
+#[cfg(test)]
+#[path = "./tests/analyze.rs"]
+mod analyze;
+
+#[cfg(test)]
+#[path = "./tests/span.rs"]
+mod span;
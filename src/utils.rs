@@ -1,4 +1,4 @@
-use swc_common::{Span, DUMMY_SP};
+use swc_common::{comments::Comments, errors::HANDLER, BytePos, Span, DUMMY_SP};
 use swc_core::ecma::{ast::*, atoms::Atom};
 
 /// Check provided name is valid React component name.
@@ -7,8 +7,21 @@ use swc_core::ecma::{ast::*, atoms::Atom};
 ///
 /// - MyComponent: `true`
 /// - myComponent: `false`
-pub fn is_componentish_name(name: &String) -> bool {
-    name.chars().nth(0).unwrap().is_uppercase()
+pub fn is_componentish_name(name: &str) -> bool {
+    name.chars().next().unwrap().is_uppercase()
+}
+
+/// Check provided name is valid React hook name.
+///
+/// Returns `true` if name is `use` or starts with `use` followed by an uppercase letter.
+///
+/// - useState: `true`
+/// - use: `true`
+/// - useful: `false`
+/// - user: `false`
+pub fn is_hook_name(name: &str) -> bool {
+    name.strip_prefix("use")
+        .is_some_and(|rest| rest.chars().next().is_none_or(|char| char.is_uppercase()))
 }
 
 /// Get symbol name from `Ident`.
@@ -16,6 +29,259 @@ pub fn get_name_from_ident(ident: &Ident) -> String {
     ident.sym.to_string()
 }
 
+/// Check provided expression is CommonJS `exports` identifier.
+///
+/// Code: `exports`
+pub fn is_exports_ident(expr: &Expr) -> bool {
+    expr.as_ident()
+        .is_some_and(|ident| &*ident.sym == "exports")
+}
+
+/// Check provided member expression is CommonJS `module.exports`.
+///
+/// Code: `module.exports`
+pub fn is_module_exports(member_expr: &MemberExpr) -> bool {
+    member_expr
+        .obj
+        .as_ident()
+        .is_some_and(|ident| &*ident.sym == "module")
+        && member_expr
+            .prop
+            .as_ident()
+            .is_some_and(|ident| &*ident.sym == "exports")
+}
+
+/// Check provided expression is CommonJS `require` call or its member.
+///
+/// Code: `require('...')`, `require('...').prop`
+pub fn is_require_expr(expr: &Expr) -> bool {
+    match expr {
+        Expr::Call(call_expr) => call_expr
+            .callee
+            .as_expr()
+            .and_then(|callee_expr| callee_expr.as_ident())
+            .is_some_and(|ident| &*ident.sym == "require"),
+        Expr::Member(member_expr) => is_require_expr(&member_expr.obj),
+        _ => false,
+    }
+}
+
+/// Report the warning at the span through the diagnostic handler.
+pub fn emit_warning(span: Span, message: &str) {
+    HANDLER.with(|handler| handler.struct_span_warn(span, message).emit());
+}
+
+/// Check leading comments at the position contains the directive.
+///
+/// Code: `// @refresh reset`
+pub fn has_leading_comment<C: Comments>(comments: &C, pos: BytePos, directive: &str) -> bool {
+    comments.get_leading(pos).is_some_and(|comments| {
+        comments
+            .iter()
+            .any(|comment| comment.text.contains(directive))
+    })
+}
+
+/// Returns directives at the top of module items.
+///
+/// Code: `"use client";`
+fn get_directives(module_items: &[ModuleItem]) -> impl Iterator<Item = &Str> {
+    module_items
+        .iter()
+        .map_while(|module_item| match module_item {
+            ModuleItem::Stmt(Stmt::Expr(ExprStmt { expr, .. })) => match &**expr {
+                Expr::Lit(Lit::Str(str)) => Some(str),
+                _ => None,
+            },
+            _ => None,
+        })
+}
+
+/// Check module items start with the directive.
+///
+/// Code: `"use client";`
+pub fn has_directive(module_items: &[ModuleItem], directive: &str) -> bool {
+    get_directives(module_items).any(|str| &*str.value == directive)
+}
+
+/// Returns count of directives at the top of module items.
+pub fn directive_count(module_items: &[ModuleItem]) -> usize {
+    get_directives(module_items).count()
+}
+
+/// Returns identifiers that declared by the module item.
+///
+/// - `function Component() {}`
+/// - `const Component = () => {};`
+/// - `export function Component() {}`
+/// - `export default function Component() {}`
+pub fn get_declared_idents(module_item: &ModuleItem) -> Vec<&Ident> {
+    let decl = match module_item {
+        ModuleItem::Stmt(Stmt::Decl(decl)) => decl,
+        ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(export_decl)) => &export_decl.decl,
+        ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultDecl(ExportDefaultDecl {
+            decl:
+                DefaultDecl::Fn(FnExpr {
+                    ident: Some(fn_ident),
+                    ..
+                }),
+            ..
+        })) => return vec![fn_ident],
+        _ => return vec![],
+    };
+
+    match decl {
+        Decl::Fn(fn_decl) => vec![&fn_decl.ident],
+        Decl::Var(var_decl) => var_decl
+            .decls
+            .iter()
+            .filter_map(|var_declarator| var_declarator.name.as_ident())
+            .map(|binding| &binding.id)
+            .collect(),
+        _ => vec![],
+    }
+}
+
+/// Returns the member expression including optional chaining.
+///
+/// - `obj.prop`
+/// - `obj?.prop`
+fn as_member_expr(expr: &Expr) -> Option<&MemberExpr> {
+    match expr {
+        Expr::Member(member_expr) => Some(member_expr),
+        Expr::OptChain(OptChainExpr { base, .. }) => base.as_member(),
+        _ => None,
+    }
+}
+
+/// Returns the handler if provided expression calls `import.meta.hot.dispose()`.
+///
+/// - `import.meta.hot.dispose(handler)`
+/// - `import.meta.hot?.dispose(handler)`
+pub fn get_import_meta_hot_dispose_handler(expr: &mut Expr) -> Option<&mut Box<Expr>> {
+    let (callee, args) = match expr {
+        Expr::Call(CallExpr {
+            callee: Callee::Expr(callee),
+            args,
+            ..
+        }) => (callee, args),
+        Expr::OptChain(OptChainExpr { base, .. }) => match &mut **base {
+            OptChainBase::Call(OptCall { callee, args, .. }) => (callee, args),
+            _ => return None,
+        },
+        _ => return None,
+    };
+
+    let is_prop = |member_expr: &MemberExpr, name: &str| {
+        member_expr
+            .prop
+            .as_ident()
+            .is_some_and(|ident| &*ident.sym == name)
+    };
+
+    let is_dispose = as_member_expr(callee)
+        .filter(|dispose_member| is_prop(dispose_member, "dispose"))
+        .and_then(|dispose_member| as_member_expr(&dispose_member.obj))
+        .filter(|hot_member| is_prop(hot_member, "hot"))
+        .is_some_and(|hot_member| {
+            matches!(
+                &*hot_member.obj,
+                Expr::MetaProp(MetaPropExpr {
+                    kind: MetaPropKind::ImportMeta,
+                    ..
+                })
+            )
+        });
+
+    args.first_mut()
+        .filter(|arg| is_dispose && arg.spread.is_none())
+        .map(|arg| &mut arg.expr)
+}
+
+/// Returns the inner expression of TypeScript expressions and parentheses.
+///
+/// - `expr as T`
+/// - `expr satisfies T`
+/// - `<T>expr`
+/// - `expr!`
+/// - `expr as const`
+/// - `(expr)`
+pub fn unwrap_expr(expr: &Expr) -> &Expr {
+    match expr {
+        Expr::TsAs(TsAsExpr { expr, .. })
+        | Expr::TsSatisfies(TsSatisfiesExpr { expr, .. })
+        | Expr::TsTypeAssertion(TsTypeAssertion { expr, .. })
+        | Expr::TsNonNull(TsNonNullExpr { expr, .. })
+        | Expr::TsConstAssertion(TsConstAssertion { expr, .. })
+        | Expr::Paren(ParenExpr { expr, .. }) => unwrap_expr(expr),
+        _ => expr,
+    }
+}
+
+/// Check provided expression calls the function with the name.
+///
+/// - `name()`
+/// - `obj.name()` (eg. `React.name()`)
+fn is_call_of(expr: &Expr, name: &str) -> bool {
+    let Some(callee_expr) = expr
+        .as_call()
+        .and_then(|call_expr| call_expr.callee.as_expr())
+    else {
+        return false;
+    };
+
+    match &**callee_expr {
+        Expr::Ident(ident) => &*ident.sym == name,
+        Expr::Member(member_expr) => member_expr
+            .prop
+            .as_ident()
+            .is_some_and(|ident| &*ident.sym == name),
+        _ => false,
+    }
+}
+
+/// Check provided expression is `createContext()` or `React.createContext()`.
+pub fn is_create_context_call(expr: &Expr) -> bool {
+    is_call_of(expr, "createContext")
+}
+
+/// Check provided expression is `lazy()` or `React.lazy()`.
+pub fn is_lazy_call(expr: &Expr) -> bool {
+    is_call_of(expr, "lazy")
+}
+
+/// Returns the name of the function called by the call expression.
+///
+/// - `hoc(Component)`: `hoc`
+/// - `React.memo(Component)`: `memo`
+/// - `connect(mapState)(Component)`: `connect`
+pub fn get_callee_name(call_expr: &CallExpr) -> Option<String> {
+    match &**call_expr.callee.as_expr()? {
+        Expr::Ident(ident) => Some(get_name_from_ident(ident)),
+        Expr::Member(member_expr) => member_expr.prop.as_ident().map(get_name_from_ident),
+        Expr::Call(call_expr) => get_callee_name(call_expr),
+        _ => None,
+    }
+}
+
+/// Returns the identifier that is wrapped by HOC calls.
+///
+/// - `Component`: `Component`
+/// - `memo(Component)`: `Component`
+/// - `memo(forwardRef(Component))`: `Component`
+/// - `connect(mapState)(Component)`: `Component`
+pub fn get_hoc_wrapped_ident(expr: &Expr) -> Option<&Ident> {
+    match unwrap_expr(expr) {
+        Expr::Ident(ident) => Some(ident),
+        Expr::Call(call_expr) => call_expr
+            .args
+            .first()
+            .filter(|arg| arg.spread.is_none())
+            .and_then(|arg| get_hoc_wrapped_ident(&arg.expr)),
+        _ => None,
+    }
+}
+
 /// Returns an identify.
 pub fn ident(sym: Atom) -> Ident {
     Ident::new(sym, DUMMY_SP)
@@ -33,11 +299,11 @@ pub fn ident_expr(sym: Atom) -> Expr {
 
 /// Returns an identify expression by string.
 pub fn ident_str_expr(sym: &String, span: Span) -> Expr {
-    Expr::Ident(ident_str(sym.into(), span))
+    Expr::Ident(ident_str(sym, span))
 }
 
 /// Returns an string literal expression.
-pub fn str_expr(value: &String) -> Expr {
+pub fn str_expr(value: &str) -> Expr {
     Expr::Lit(Lit::Str(Str {
         span: DUMMY_SP,
         value: value.to_owned().into(),
@@ -50,6 +316,34 @@ pub fn bool_expr(value: bool) -> Expr {
     Expr::Lit(Lit::Bool(value.into()))
 }
 
+/// Returns an array literal expression.
+///
+/// Code: `[elem1, elem2, ...]`
+pub fn array_expr(elems: Vec<Expr>) -> Expr {
+    Expr::Array(ArrayLit {
+        span: DUMMY_SP,
+        elems: elems.into_iter().map(|elem| Some(arg_expr(elem))).collect(),
+    })
+}
+
+/// Returns an object literal expression.
+///
+/// Code: `{ key1: value1, key2: value2, ... }`
+pub fn object_expr(props: Vec<(&str, Expr)>) -> Expr {
+    Expr::Object(ObjectLit {
+        span: DUMMY_SP,
+        props: props
+            .into_iter()
+            .map(|(key, value)| {
+                PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
+                    key: PropName::Ident(ident(key.into())),
+                    value: Box::new(value),
+                })))
+            })
+            .collect(),
+    })
+}
+
 /// Returns an function argument expression.
 pub fn arg_expr(expr: Expr) -> ExprOrSpread {
     ExprOrSpread {
@@ -69,6 +363,21 @@ pub fn obj_prop_expr(obj: Expr, prop: Ident) -> Expr {
     })
 }
 
+/// Returns an arrow function expression that returns the expression.
+///
+/// Code: `() => expr`
+pub fn arrow_expr(expr: Expr) -> Expr {
+    Expr::Arrow(ArrowExpr {
+        span: DUMMY_SP,
+        params: vec![],
+        body: Box::new(BlockStmtOrExpr::Expr(Box::new(expr))),
+        is_async: false,
+        is_generator: false,
+        type_params: None,
+        return_type: None,
+    })
+}
+
 /// Returns an expression that assign right to left.
 ///
 /// Code: `left = right`
@@ -85,14 +394,35 @@ pub fn assign_expr(left: Expr, right: Expr) -> Expr {
 ///
 /// Code: `callee(arg1, arg2, ...)`
 pub fn call_expr(callee: Expr, args: Vec<ExprOrSpread>) -> Expr {
+    call_expr_with_span(callee, args, DUMMY_SP)
+}
+
+/// Returns an expression that call function with arguments at the span.
+pub fn call_expr_with_span(callee: Expr, args: Vec<ExprOrSpread>, span: Span) -> Expr {
     Expr::Call(CallExpr {
-        span: DUMMY_SP,
+        span,
         callee: Callee::Expr(Box::new(callee)),
-        args: args,
+        args,
         type_args: None,
     })
 }
 
+/// Returns an expression that call function only if the function exists.
+///
+/// Code: `callee?.(arg1, arg2, ...)`
+pub fn opt_call_expr(callee: Expr, args: Vec<ExprOrSpread>) -> Expr {
+    Expr::OptChain(OptChainExpr {
+        span: DUMMY_SP,
+        optional: true,
+        base: Box::new(OptChainBase::Call(OptCall {
+            span: DUMMY_SP,
+            callee: Box::new(callee),
+            args,
+            type_args: None,
+        })),
+    })
+}
+
 /// Returns a statement that declare variable and assign.
 ///
 /// Code: `var name = init;`;
@@ -115,8 +445,13 @@ pub fn decl_var_and_assign_stmt(name: Ident, init: Expr) -> Stmt {
 
 /// Returns expr statement.
 pub fn to_stmt(expr: Expr) -> Stmt {
+    to_stmt_with_span(expr, DUMMY_SP)
+}
+
+/// Returns expr statement at the span.
+pub fn to_stmt_with_span(expr: Expr, span: Span) -> Stmt {
     Stmt::Expr(ExprStmt {
-        span: DUMMY_SP,
+        span,
         expr: Box::new(expr),
     })
 }
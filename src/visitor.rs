@@ -1,17 +1,29 @@
 #![cfg_attr(not(debug_assertions), allow(dead_code))]
 
-use crate::utils::{get_name_from_ident, is_componentish_name};
-use std::collections::HashSet;
-use swc_core::ecma::{ast::*, visit::Visit};
+use crate::utils::{
+    emit_warning, get_declared_idents, get_hoc_wrapped_ident, get_name_from_ident,
+    has_leading_comment, is_componentish_name, is_exports_ident, is_module_exports,
+    is_require_expr, unwrap_expr,
+};
+use std::collections::{HashMap, HashSet};
+use swc_common::{comments::Comments, Span, Spanned, DUMMY_SP};
+use swc_core::ecma::{
+    ast::*,
+    visit::{Visit, VisitWith},
+};
 
-/// Visit top-level to find external and class components.
-pub struct IgnoreIdentifierCollector {
+const REFRESH_SKIP_DIRECTIVE: &str = "@refresh skip";
+
+/// Visit top-level to find external, class and skipped components.
+pub struct IgnoreIdentifierCollector<'a, C: Comments> {
+    comments: &'a Option<C>,
     black_list: HashSet<String>,
 }
 
-impl IgnoreIdentifierCollector {
-    fn default() -> IgnoreIdentifierCollector {
+impl<'a, C: Comments> IgnoreIdentifierCollector<'a, C> {
+    fn default(comments: &'a Option<C>) -> IgnoreIdentifierCollector<'a, C> {
         IgnoreIdentifierCollector {
+            comments,
             black_list: HashSet::new(),
         }
     }
@@ -25,7 +37,19 @@ impl IgnoreIdentifierCollector {
     }
 }
 
-impl Visit for IgnoreIdentifierCollector {
+impl<C: Comments> Visit for IgnoreIdentifierCollector<'_, C> {
+    fn visit_module_item(&mut self, module_item: &ModuleItem) {
+        // Ignore components that are marked to skip.
+        //
+        // - `// @refresh skip`
+        if has_leading_comment(self.comments, module_item.span_lo(), REFRESH_SKIP_DIRECTIVE) {
+            for ident in get_declared_idents(module_item) {
+                self.add(get_name_from_ident(ident));
+            }
+        }
+        module_item.visit_children_with(self);
+    }
+
     fn visit_import_specifiers(&mut self, import_specifiers: &[ImportSpecifier]) {
         for import_specifier in import_specifiers.iter() {
             // Ignore external components.
@@ -50,6 +74,21 @@ impl Visit for IgnoreIdentifierCollector {
         }
     }
 
+    fn visit_var_declarator(&mut self, var_declarator: &VarDeclarator) {
+        // Ignore external components from CommonJS modules.
+        //
+        // - `var Component = require('...');`
+        // - `var Component = require('...').Component;`
+        if let (Some(binding), Some(init_expr)) =
+            (var_declarator.name.as_ident(), &var_declarator.init)
+        {
+            let identifier = get_name_from_ident(&binding.id);
+            if is_componentish_name(&identifier) && is_require_expr(init_expr) {
+                self.add(identifier);
+            }
+        }
+    }
+
     fn visit_class_decl(&mut self, class_decl: &ClassDecl) {
         // Ignore class component
         let identifier = get_name_from_ident(&class_decl.ident);
@@ -59,6 +98,481 @@ impl Visit for IgnoreIdentifierCollector {
     }
 }
 
-pub fn black_list_collector() -> IgnoreIdentifierCollector {
-    IgnoreIdentifierCollector::default()
+pub fn black_list_collector<C: Comments>(comments: &Option<C>) -> IgnoreIdentifierCollector<'_, C> {
+    IgnoreIdentifierCollector::default(comments)
+}
+
+/// Hooks imported from the hook sources. (eg. `react`)
+#[derive(Default)]
+pub struct HookImports {
+    /// Local name and its imported name.
+    ///
+    /// - `import { useState } from 'react';`
+    /// - `import { useState as useS } from 'react';`
+    pub named: HashMap<String, String>,
+    /// Local names of default and namespace imports.
+    ///
+    /// - `import React from 'react';`
+    /// - `import * as R from 'react';`
+    pub namespaces: HashSet<String>,
+}
+
+/// Visit top-level to find hooks imported from the hook sources.
+pub struct HookImportCollector<'a> {
+    hook_sources: &'a HashSet<String>,
+    imports: HookImports,
+}
+
+impl HookImportCollector<'_> {
+    pub fn get_imports(self) -> HookImports {
+        self.imports
+    }
+}
+
+impl Visit for HookImportCollector<'_> {
+    fn visit_import_decl(&mut self, import_decl: &ImportDecl) {
+        if import_decl.type_only || !self.hook_sources.contains(&*import_decl.src.value) {
+            return;
+        }
+
+        for import_specifier in import_decl.specifiers.iter() {
+            match import_specifier {
+                ImportSpecifier::Named(named_import) if !named_import.is_type_only => {
+                    let local = get_name_from_ident(&named_import.local);
+                    let imported = named_import
+                        .imported
+                        .as_ref()
+                        .map_or(local.to_owned(), get_name_from_export_name);
+                    self.imports.named.insert(local, imported);
+                }
+                ImportSpecifier::Named(_) => (),
+                ImportSpecifier::Default(ImportDefaultSpecifier { local, .. })
+                | ImportSpecifier::Namespace(ImportStarAsSpecifier { local, .. }) => {
+                    self.imports.namespaces.insert(get_name_from_ident(local));
+                }
+            }
+        }
+    }
+}
+
+pub fn hook_import_collector(hook_sources: &HashSet<String>) -> HookImportCollector<'_> {
+    HookImportCollector {
+        hook_sources,
+        imports: HookImports::default(),
+    }
+}
+
+/// Exported name and its local identifier.
+pub struct ExportMeta {
+    pub name: String,
+    /// Span of the module item that exports the value.
+    pub span: Span,
+    /// Local identifier of exported value.
+    ///
+    /// `None` when the value is not defined in the module (eg. re-exports).
+    pub local: Option<String>,
+}
+
+/// Visit top-level to find exported names.
+pub struct ExportCollector {
+    exports: Vec<ExportMeta>,
+    /// Span of the module item that is currently visited.
+    span: Span,
+}
+
+impl ExportCollector {
+    fn default() -> ExportCollector {
+        ExportCollector {
+            exports: Vec::new(),
+            span: DUMMY_SP,
+        }
+    }
+
+    fn add(&mut self, name: String, local: Option<String>) {
+        if let Some(export) = self.exports.iter_mut().find(|export| export.name == name) {
+            export.local = local;
+            export.span = self.span;
+        } else {
+            self.exports.push(ExportMeta {
+                name,
+                span: self.span,
+                local,
+            });
+        }
+    }
+
+    pub fn get_exports(self) -> Vec<ExportMeta> {
+        self.exports
+    }
+
+    fn collect_decl(&mut self, decl: &Decl) {
+        match decl {
+            Decl::Fn(FnDecl { ident, .. }) | Decl::Class(ClassDecl { ident, .. }) => {
+                let identifier = get_name_from_ident(ident);
+                self.add(identifier.to_owned(), Some(identifier));
+            }
+            Decl::TsEnum(ts_enum) => {
+                let identifier = get_name_from_ident(&ts_enum.id);
+                self.add(identifier.to_owned(), Some(identifier));
+            }
+            Decl::Var(var_decl) => {
+                for var_declarator in var_decl.decls.iter() {
+                    if let Some(binding) = var_declarator.name.as_ident() {
+                        let identifier = get_name_from_ident(&binding.id);
+                        self.add(identifier.to_owned(), Some(identifier));
+                    }
+                }
+            }
+            _ => (),
+        }
+    }
+
+    fn collect_named_export(&mut self, named_export: &NamedExport) {
+        if named_export.type_only {
+            return;
+        }
+
+        for specifier in named_export.specifiers.iter() {
+            match specifier {
+                // - `export { Component };`
+                // - `export { Component as Renamed };`
+                // - `export { Component } from '...';`
+                ExportSpecifier::Named(named_specifier) if !named_specifier.is_type_only => {
+                    let local = get_name_from_export_name(&named_specifier.orig);
+                    let name = named_specifier
+                        .exported
+                        .as_ref()
+                        .map_or(local.to_owned(), get_name_from_export_name);
+                    self.add(name, named_export.src.is_none().then_some(local));
+                }
+                // - `export * as ns from '...';`
+                ExportSpecifier::Namespace(namespace_specifier) => {
+                    self.add(get_name_from_export_name(&namespace_specifier.name), None);
+                }
+                // - `export v from '...';`
+                ExportSpecifier::Default(default_specifier) => {
+                    self.add(get_name_from_ident(&default_specifier.exported), None);
+                }
+                _ => (),
+            }
+        }
+    }
+
+    fn collect_commonjs_export(&mut self, expr: &Expr) {
+        match expr {
+            Expr::Assign(AssignExpr {
+                op: AssignOp::Assign,
+                left,
+                right,
+                ..
+            }) => {
+                let Some(Expr::Member(export_target)) = left.as_expr() else {
+                    return;
+                };
+
+                if is_module_exports(export_target) {
+                    // - `module.exports = Component;`
+                    // - `module.exports = function Component() {};`
+                    let local = match &**right {
+                        Expr::Ident(ident) => Some(get_name_from_ident(ident)),
+                        Expr::Fn(FnExpr {
+                            ident: Some(fn_ident),
+                            ..
+                        }) => Some(get_name_from_ident(fn_ident)),
+                        _ => None,
+                    };
+                    self.add(String::from("default"), local);
+                } else if let Some(prop_ident) = export_target.prop.as_ident() {
+                    // - `exports.Component = Component;`
+                    // - `module.exports.Component = () => {};`
+                    if is_exports_ident(&export_target.obj)
+                        || export_target.obj.as_member().is_some_and(is_module_exports)
+                    {
+                        let name = get_name_from_ident(prop_ident);
+                        let local = match &**right {
+                            Expr::Ident(ident) => Some(get_name_from_ident(ident)),
+                            Expr::Fn(_) | Expr::Arrow(_) | Expr::Call(_) => Some(name.to_owned()),
+                            _ => None,
+                        };
+                        self.add(name, local);
+                    }
+                }
+            }
+            // - `Object.defineProperty(exports, 'Component', { get: function () { return Component; } });`
+            Expr::Call(call_expr) if is_define_exports_property(call_expr) => {
+                let name = match call_expr.args.get(1).map(|arg| &*arg.expr) {
+                    Some(Expr::Lit(Lit::Str(str))) => str.value.to_string(),
+                    _ => return,
+                };
+
+                if name != "__esModule" {
+                    let local = call_expr
+                        .args
+                        .get(2)
+                        .and_then(|arg| arg.expr.as_object())
+                        .and_then(get_getter_return_ident)
+                        .map(get_name_from_ident);
+                    self.add(name, local);
+                }
+            }
+            _ => (),
+        }
+    }
+}
+
+impl Visit for ExportCollector {
+    fn visit_module_item(&mut self, module_item: &ModuleItem) {
+        self.span = module_item.span();
+        match module_item {
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(export_decl)) => {
+                self.collect_decl(&export_decl.decl);
+            }
+            ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(named_export)) => {
+                self.collect_named_export(named_export);
+            }
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultDecl(default_decl)) => {
+                let local = match &default_decl.decl {
+                    DefaultDecl::Fn(FnExpr { ident, .. })
+                    | DefaultDecl::Class(ClassExpr { ident, .. }) => {
+                        ident.as_ref().map(get_name_from_ident)
+                    }
+                    _ => None,
+                };
+                self.add(String::from("default"), local);
+            }
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultExpr(ExportDefaultExpr {
+                expr,
+                ..
+            }))
+            | ModuleItem::ModuleDecl(ModuleDecl::TsExportAssignment(TsExportAssignment {
+                expr,
+                ..
+            })) => {
+                // HOCs that wrap the local value are resolved. (eg. `export default memo(Component);`)
+                let local = get_hoc_wrapped_ident(expr).map(get_name_from_ident);
+                self.add(String::from("default"), local);
+            }
+            // Exported names can't be resolved statically.
+            ModuleItem::ModuleDecl(ModuleDecl::ExportAll(_)) => {
+                self.add(String::from("*"), None);
+            }
+            ModuleItem::Stmt(Stmt::Expr(ExprStmt { expr, .. })) => {
+                self.collect_commonjs_export(expr);
+            }
+            _ => (),
+        }
+    }
+}
+
+pub fn export_collector() -> ExportCollector {
+    ExportCollector::default()
+}
+
+/// Visit top-level to report components that can't be refreshed.
+pub struct SkippedComponentReporter;
+
+impl SkippedComponentReporter {
+    /// Report components that are declared with other variables.
+    ///
+    /// Code: `var A = () => {}, B = () => {};`
+    fn report_multi_declarator(&self, var_decl: &VarDecl) {
+        if var_decl.decls.len() < 2 {
+            return;
+        }
+
+        for var_declarator in var_decl.decls.iter() {
+            if let (Some(binding), Some(init_expr)) =
+                (var_declarator.name.as_ident(), &var_declarator.init)
+            {
+                let identifier = get_name_from_ident(&binding.id);
+                if is_componentish_name(&identifier)
+                    && matches!(
+                        unwrap_expr(init_expr),
+                        Expr::Fn(_) | Expr::Arrow(_) | Expr::Call(_)
+                    )
+                {
+                    emit_warning(
+                        var_declarator.span,
+                        &format!(
+                            "Component \"{}\" can't be refreshed because it is declared with other variables. Declare it in a separate statement.",
+                            identifier
+                        ),
+                    );
+                }
+            }
+        }
+    }
+
+    /// Report class components.
+    ///
+    /// Code: `class Component extends React.Component {}`
+    fn report_class(&self, ident: &Ident, class: &Class) {
+        let identifier = get_name_from_ident(ident);
+        if is_componentish_name(&identifier)
+            && class.super_class.as_deref().is_some_and(is_component_class)
+        {
+            emit_warning(
+                class.span,
+                &format!(
+                    "Class component \"{}\" can't be refreshed. It is remounted on every update.",
+                    identifier
+                ),
+            );
+        }
+    }
+
+    /// Report anonymous components.
+    ///
+    /// Code: `export default () => {};`
+    fn report_anonymous(&self, span: Span) {
+        emit_warning(
+            span,
+            "Anonymous default export can't be refreshed. Name the component to preserve its state.",
+        );
+    }
+}
+
+impl Visit for SkippedComponentReporter {
+    fn visit_module_item(&mut self, module_item: &ModuleItem) {
+        let decl = match module_item {
+            // Exported declarations are not reported. (Only non-exported `var`s)
+            ModuleItem::Stmt(Stmt::Decl(Decl::Var(var_decl))) => {
+                self.report_multi_declarator(var_decl);
+                return;
+            }
+            ModuleItem::Stmt(Stmt::Decl(decl)) => decl,
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(export_decl)) => &export_decl.decl,
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultDecl(default_decl)) => {
+                match &default_decl.decl {
+                    DefaultDecl::Fn(FnExpr { ident: None, .. }) => {
+                        self.report_anonymous(default_decl.span);
+                    }
+                    DefaultDecl::Class(ClassExpr {
+                        ident: Some(ident),
+                        class,
+                    }) => self.report_class(ident, class),
+                    _ => (),
+                }
+                return;
+            }
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultExpr(default_expr)) => {
+                if matches!(
+                    unwrap_expr(&default_expr.expr),
+                    Expr::Arrow(_) | Expr::Fn(FnExpr { ident: None, .. })
+                ) {
+                    self.report_anonymous(default_expr.span);
+                }
+                return;
+            }
+            _ => return,
+        };
+
+        if let Decl::Class(class_decl) = decl {
+            self.report_class(&class_decl.ident, &class_decl.class);
+        }
+    }
+}
+
+pub fn skipped_component_reporter() -> SkippedComponentReporter {
+    SkippedComponentReporter
+}
+
+/// Visit all identifiers to find names that are used in the module.
+#[derive(Default)]
+pub struct IdentNameCollector {
+    names: HashSet<String>,
+}
+
+impl IdentNameCollector {
+    pub fn get_names(self) -> HashSet<String> {
+        self.names
+    }
+}
+
+impl Visit for IdentNameCollector {
+    fn visit_ident(&mut self, ident: &Ident) {
+        self.names.insert(get_name_from_ident(ident));
+    }
+}
+
+pub fn ident_name_collector() -> IdentNameCollector {
+    IdentNameCollector::default()
+}
+
+/// Check provided super class is React component class.
+///
+/// Code: `Component`, `PureComponent`, `React.Component`, `React.PureComponent`
+fn is_component_class(super_class: &Expr) -> bool {
+    let is_component_ident = |ident: &Ident| matches!(&*ident.sym, "Component" | "PureComponent");
+
+    match unwrap_expr(super_class) {
+        Expr::Ident(ident) => is_component_ident(ident),
+        Expr::Member(member_expr) => {
+            member_expr.obj.is_ident()
+                && member_expr.prop.as_ident().is_some_and(is_component_ident)
+        }
+        _ => false,
+    }
+}
+
+/// Get name from export specifier.
+fn get_name_from_export_name(export_name: &ModuleExportName) -> String {
+    match export_name {
+        ModuleExportName::Ident(ident) => get_name_from_ident(ident),
+        ModuleExportName::Str(str) => str.value.to_string(),
+    }
+}
+
+/// Check provided call expression defines property of CommonJS `exports`.
+///
+/// Code: `Object.defineProperty(exports, ...)`
+fn is_define_exports_property(call_expr: &CallExpr) -> bool {
+    let is_define_property = call_expr
+        .callee
+        .as_expr()
+        .and_then(|callee_expr| callee_expr.as_member())
+        .is_some_and(|member_expr| {
+            member_expr
+                .obj
+                .as_ident()
+                .is_some_and(|ident| &*ident.sym == "Object")
+                && member_expr
+                    .prop
+                    .as_ident()
+                    .is_some_and(|ident| &*ident.sym == "defineProperty")
+        });
+
+    is_define_property
+        && call_expr
+            .args
+            .first()
+            .is_some_and(|arg| is_exports_ident(&arg.expr))
+}
+
+/// Returns the identifier returned by the property getter.
+///
+/// - `{ get: function () { return Component; } }`
+/// - `{ get() { return Component; } }`
+/// - `{ get: () => Component }`
+fn get_getter_return_ident(object_lit: &ObjectLit) -> Option<&Ident> {
+    let is_getter_key = |key: &PropName| key.as_ident().is_some_and(|ident| &*ident.sym == "get");
+
+    object_lit.props.iter().find_map(|prop| {
+        let stmts = match prop.as_prop()?.as_ref() {
+            Prop::KeyValue(key_value) if is_getter_key(&key_value.key) => match &*key_value.value {
+                Expr::Fn(fn_expr) => &fn_expr.function.body.as_ref()?.stmts,
+                Expr::Arrow(arrow_expr) => match &*arrow_expr.body {
+                    BlockStmtOrExpr::Expr(expr) => return expr.as_ident(),
+                    BlockStmtOrExpr::BlockStmt(block_stmt) => &block_stmt.stmts,
+                },
+                _ => return None,
+            },
+            Prop::Method(method) if is_getter_key(&method.key) => {
+                &method.function.body.as_ref()?.stmts
+            }
+            _ => return None,
+        };
+
+        stmts.first()?.as_return_stmt()?.arg.as_ref()?.as_ident()
+    })
 }
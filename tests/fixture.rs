@@ -0,0 +1,49 @@
+use std::{
+    fs::read_to_string,
+    path::{Path, PathBuf},
+};
+use swc_core::ecma::transforms::testing::{test_fixture, FixtureTestConfig};
+use swc_ecma_parser::{EsConfig, Syntax, TsConfig};
+use swc_plugin_react_refresh::{react_refresh, Config};
+
+/// Returns the syntax by the extension of the input file.
+fn get_syntax(input: &Path) -> Syntax {
+    match input.extension().and_then(|extension| extension.to_str()) {
+        Some("ts") => Syntax::Typescript(Default::default()),
+        Some("tsx") => Syntax::Typescript(TsConfig {
+            tsx: true,
+            ..Default::default()
+        }),
+        _ => Syntax::Es(EsConfig {
+            jsx: true,
+            ..Default::default()
+        }),
+    }
+}
+
+/// Returns the config from `config.json` next to the input file.
+///
+/// Defaults to `{ "moduleId": "test" }`.
+fn get_config(input: &Path) -> Config {
+    match read_to_string(input.with_file_name("config.json")) {
+        Ok(config) => serde_json::from_str(&config).expect("invalid config.json"),
+        Err(_) => Config {
+            module_id: String::from("test"),
+            ..Default::default()
+        },
+    }
+}
+
+/// Run `UPDATE=1 cargo test` to update `output.js` of fixtures.
+#[testing::fixture("tests/fixtures/**/input.*")]
+fn fixture(input: PathBuf) {
+    let output = input.with_file_name("output.js");
+
+    test_fixture(
+        get_syntax(&input),
+        &|tester| react_refresh(get_config(&input), Some(tester.comments.clone())),
+        &input,
+        &output,
+        FixtureTestConfig::default(),
+    );
+}
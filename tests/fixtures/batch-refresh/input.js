@@ -0,0 +1,6 @@
+export function Screen() {
+  const [visible, setVisible] = useState(false);
+  return <div>{visible ? 'visible' : 'hidden'}</div>;
+}
+
+export const formatTitle = (title) => title.trim();
@@ -0,0 +1,27 @@
+var __prevRefreshReg = global.$RefreshReg$;
+var __prevRefreshSig = global.$RefreshSig$;
+global.$RefreshReg$ = global.$RefreshRuntime$.getRegisterFunction();
+global.$RefreshSig$ = global.$RefreshRuntime$.getCreateSignatureFunction();
+var __s = global.$RefreshSig$();
+export function Screen() {
+    __s();
+    const [visible, setVisible] = useState(false);
+    return <div>{visible ? 'visible' : 'hidden'}</div>;
+}
+export const formatTitle = (title)=>title.trim();
+global.$RefreshRuntime$.beginModule("src/Screen.js");
+__s(Screen, "src/Screen.js:Screen", false);
+global.$RefreshReg$(Screen, "Screen");
+global.$RefreshRuntime$.registerModule?.("src/Screen.js", {
+    exports: [
+        "Screen",
+        "formatTitle"
+    ],
+    components: [
+        "Screen"
+    ],
+    isBoundary: false
+});
+global.$RefreshRuntime$.endModule("src/Screen.js");
+global.$RefreshReg$ = __prevRefreshReg;
+global.$RefreshSig$ = __prevRefreshSig;
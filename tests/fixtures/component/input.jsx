@@ -0,0 +1,18 @@
+import React from 'react';
+
+export function Header({ title }) {
+  return <h1>{title}</h1>;
+}
+
+export const Footer = () => {
+  return <footer>Footer</footer>;
+};
+
+export default function App() {
+  return (
+    <>
+      <Header title="Hello" />
+      <Footer />
+    </>
+  );
+}
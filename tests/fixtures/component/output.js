@@ -0,0 +1,40 @@
+var __prevRefreshReg = global.$RefreshReg$;
+var __prevRefreshSig = global.$RefreshSig$;
+global.$RefreshReg$ = global.$RefreshRuntime$.getRegisterFunction();
+import React from 'react';
+export function Header({ title }) {
+    return <h1>{title}</h1>;
+}
+export const Footer = ()=>{
+    return <footer>Footer</footer>;
+};
+export default function App() {
+    return <>
+
+      <Header title="Hello"/>
+
+      <Footer/>
+
+    </>;
+}
+global.$RefreshReg$(Header, "Header");
+global.$RefreshRuntime$.getContext(Header).accept();
+global.$RefreshReg$(Footer, "Footer");
+global.$RefreshRuntime$.getContext(Footer).accept();
+global.$RefreshReg$(App, "App");
+global.$RefreshRuntime$.getContext(App).accept();
+global.$RefreshRuntime$.registerModule?.("test", {
+    exports: [
+        "Header",
+        "Footer",
+        "default"
+    ],
+    components: [
+        "Header",
+        "Footer",
+        "App"
+    ],
+    isBoundary: true
+});
+global.$RefreshReg$ = __prevRefreshReg;
+global.$RefreshSig$ = __prevRefreshSig;
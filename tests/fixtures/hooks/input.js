@@ -0,0 +1,15 @@
+import { useState, useEffect as useMountEffect } from 'react';
+import { useTheme } from './theme';
+
+export function Counter() {
+  const [count, setCount] = useState(0);
+  useMountEffect(() => {
+    document.title = `${count}`;
+  }, [count]);
+  return <button onClick={() => setCount(count + 1)}>{count}</button>;
+}
+
+export function ThemedButton({ children }) {
+  const theme = useTheme();
+  return <button style={{ color: theme.color }}>{children}</button>;
+}
@@ -0,0 +1,43 @@
+var __prevRefreshReg = global.$RefreshReg$;
+var __prevRefreshSig = global.$RefreshSig$;
+global.$RefreshReg$ = global.$RefreshRuntime$.getRegisterFunction();
+global.$RefreshSig$ = global.$RefreshRuntime$.getCreateSignatureFunction();
+var __s = global.$RefreshSig$();
+import { useState, useEffect as useMountEffect } from 'react';
+import { useTheme } from './theme';
+export function Counter() {
+    __s();
+    const [count, setCount] = useState(0);
+    useMountEffect(()=>{
+        document.title = `${count}`;
+    }, [
+        count
+    ]);
+    return <button onClick={()=>setCount(count + 1)}>{count}</button>;
+}
+export function ThemedButton({ children }) {
+    __s();
+    const theme = useTheme();
+    return <button style={{
+        color: theme.color
+    }}>{children}</button>;
+}
+__s(Counter, "test:Counter", false);
+global.$RefreshReg$(Counter, "Counter");
+global.$RefreshRuntime$.getContext(Counter).accept();
+__s(ThemedButton, "test:ThemedButton", true);
+global.$RefreshReg$(ThemedButton, "ThemedButton");
+global.$RefreshRuntime$.getContext(ThemedButton).accept();
+global.$RefreshRuntime$.registerModule?.("test", {
+    exports: [
+        "Counter",
+        "ThemedButton"
+    ],
+    components: [
+        "Counter",
+        "ThemedButton"
+    ],
+    isBoundary: true
+});
+global.$RefreshReg$ = __prevRefreshReg;
+global.$RefreshSig$ = __prevRefreshSig;
@@ -0,0 +1,14 @@
+import { useState, type FC } from 'react';
+
+interface Props {
+  initialValue: number;
+}
+
+export const Counter = ((props: Props) => {
+  const [value, setValue] = useState<number>(props.initialValue);
+  return <div>{value}</div>;
+}) satisfies FC<Props>;
+
+export const Label = function ({ text }: { text: string }) {
+  return <span>{text}</span>;
+} as FC<{ text: string }>;
@@ -0,0 +1,39 @@
+var __prevRefreshReg = global.$RefreshReg$;
+var __prevRefreshSig = global.$RefreshSig$;
+global.$RefreshReg$ = global.$RefreshRuntime$.getRegisterFunction();
+global.$RefreshSig$ = global.$RefreshRuntime$.getCreateSignatureFunction();
+var __s = global.$RefreshSig$();
+import { useState, type FC } from 'react';
+interface Props {
+    initialValue: number;
+}
+export const Counter = (props: Props)=>{
+    __s();
+    const [value, setValue] = useState<number>(props.initialValue);
+    return <div>{value}</div>;
+} satisfies FC<Props>;
+export const Label = function({ text }: {
+    text: string;
+}) {
+    return <span>{text}</span>;
+} as FC<{
+    text: string;
+}>;
+__s(Counter, "test:Counter", false);
+global.$RefreshReg$(Counter, "Counter");
+global.$RefreshRuntime$.getContext(Counter).accept();
+global.$RefreshReg$(Label, "Label");
+global.$RefreshRuntime$.getContext(Label).accept();
+global.$RefreshRuntime$.registerModule?.("test", {
+    exports: [
+        "Counter",
+        "Label"
+    ],
+    components: [
+        "Counter",
+        "Label"
+    ],
+    isBoundary: true
+});
+global.$RefreshReg$ = __prevRefreshReg;
+global.$RefreshSig$ = __prevRefreshSig;